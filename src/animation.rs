@@ -0,0 +1,393 @@
+use std::{
+    collections::HashMap,
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+use crate::{
+    regions::Bounds,
+    render::{Canvas, Colour, Image},
+};
+
+/// Collects pictures of a simulation as it runs, keeping every `every`th step and optionally
+/// cropping each one. Frames are drawn lazily, so skipped steps cost nothing to draw
+#[derive(Clone, Debug)]
+pub struct Recorder {
+    every: usize,
+    crop: Option<Bounds>,
+    steps: usize,
+    /// Whether the latest step was kept, so that `finish` doesn't keep it twice
+    kept_last: bool,
+    frames: Vec<Canvas>,
+}
+
+impl Recorder {
+    /// A recorder keeping every `every`th step, starting with the first. Panics if `every` is zero
+    pub fn new(every: usize) -> Recorder {
+        assert!(every > 0, "a recorder has to keep some frames");
+        Recorder {
+            every,
+            crop: None,
+            steps: 0,
+            kept_last: false,
+            frames: Vec::new(),
+        }
+    }
+
+    /// Keeps only the part of each frame inside `bounds`
+    pub fn cropped(self, bounds: Bounds) -> Recorder {
+        Recorder {
+            crop: Some(bounds),
+            ..self
+        }
+    }
+
+    /// Counts a step of the simulation, drawing it with `draw` if it is one to keep
+    pub fn record<F: FnOnce() -> Canvas>(&mut self, draw: F) {
+        self.kept_last = self.steps.is_multiple_of(self.every);
+        if self.kept_last {
+            self.keep(draw());
+        }
+        self.steps += 1;
+    }
+
+    /// Keeps the final state of the simulation even if frame skipping would have dropped it, so
+    /// that an animation always ends where the simulation did
+    pub fn finish<F: FnOnce() -> Canvas>(&mut self, draw: F) {
+        if !self.kept_last {
+            self.keep(draw());
+            self.kept_last = true;
+        }
+    }
+
+    fn keep(&mut self, frame: Canvas) {
+        let frame = match self.crop {
+            Some(bounds) => frame.crop(bounds),
+            None => Some(frame),
+        };
+        // A crop that misses the picture leaves nothing to keep
+        self.frames.extend(frame);
+    }
+
+    /// How many steps have been recorded, kept or not
+    pub fn steps(&self) -> usize {
+        self.steps
+    }
+
+    pub fn frames(&self) -> &[Canvas] {
+        &self.frames
+    }
+
+    /// Writes the frames as an animated GIF with `delay` hundredths of a second between them, or
+    /// as a numbered sequence of PNGs if `path` isn't a `.gif`, returning the files written
+    pub fn save(&self, path: &Path, scale: usize, delay: u16) -> io::Result<Vec<PathBuf>> {
+        let is_gif = path.extension().is_some_and(|extension| {
+            extension.to_str().map(str::to_ascii_lowercase) == Some(String::from("gif"))
+        });
+        let images = self.frames.iter().map(|frame| frame.to_image(scale));
+        if is_gif {
+            // Check the size before drawing frames that could never be encoded
+            if let Some(frame) = self.frames.first() {
+                gif_size(frame.width() * scale, frame.height() * scale)?;
+            }
+            fs::write(path, to_gif(&images.collect::<Vec<_>>(), delay)?)?;
+            return Ok(vec![path.to_path_buf()]);
+        }
+        fs::create_dir_all(path)?;
+        let mut written = Vec::new();
+        for (number, image) in images.enumerate() {
+            let file = path.join(format!("frame_{number:05}.png"));
+            image.save(&file)?;
+            written.push(file);
+        }
+        Ok(written)
+    }
+}
+
+/// Encodes images of the same size as a looping GIF, showing each for `delay` hundredths of a
+/// second. A GIF has at most 256 colours, so busier pictures are reduced to a fixed palette, and
+/// its sides are at most 65535 pixels, so larger frames are an error
+pub fn to_gif(images: &[Image], delay: u16) -> io::Result<Vec<u8>> {
+    let (width, height) = images
+        .first()
+        .map_or((0, 0), |image| (image.width, image.height));
+    let (width, height) = gif_size(width, height)?;
+    let mut colours = HashMap::new();
+    for image in images {
+        for pixel in image.pixels.chunks(3) {
+            let next = colours.len();
+            colours
+                .entry(Colour::rgb(pixel[0], pixel[1], pixel[2]))
+                .or_insert(next);
+        }
+    }
+    let reduced = colours.len() > 256;
+    let mut palette = vec![Colour::BLACK; 256];
+    if reduced {
+        for (index, colour) in palette.iter_mut().enumerate().take(216) {
+            *colour = cube_colour(index);
+        }
+    } else {
+        for (&colour, &index) in &colours {
+            palette[index] = colour;
+        }
+    }
+    // The smallest power of two holding the palette, but no fewer than the four LZW needs
+    let bits = (2..=8)
+        .find(|&bits| 1 << bits >= colours.len().min(256))
+        .expect("256 colours fit in 8 bits");
+    let palette = &palette[..1 << bits];
+
+    let mut gif = b"GIF89a".to_vec();
+    gif.extend(width.to_le_bytes());
+    gif.extend(height.to_le_bytes());
+    // A global colour table of 2^bits entries, with 8 bits per primary
+    gif.extend([0xf0 | (bits - 1), 0, 0]);
+    for colour in palette {
+        gif.extend([colour.r, colour.g, colour.b]);
+    }
+    // Loop forever
+    gif.extend(b"\x21\xff\x0bNETSCAPE2.0\x03\x01\x00\x00\x00");
+
+    for image in images {
+        gif.extend([0x21, 0xf9, 4, 0]);
+        gif.extend(delay.to_le_bytes());
+        gif.extend([0, 0]);
+        gif.push(0x2c);
+        gif.extend([0, 0, 0, 0]);
+        gif.extend(width.to_le_bytes());
+        gif.extend(height.to_le_bytes());
+        gif.push(0);
+
+        let indices = image
+            .pixels
+            .chunks(3)
+            .map(|pixel| {
+                if reduced {
+                    cube_index(pixel)
+                } else {
+                    colours[&Colour::rgb(pixel[0], pixel[1], pixel[2])] as u8
+                }
+            })
+            .collect::<Vec<u8>>();
+        gif.push(bits);
+        for block in lzw(&indices, bits).chunks(255) {
+            gif.push(block.len() as u8);
+            gif.extend(block);
+        }
+        gif.push(0);
+    }
+    gif.push(0x3b);
+    Ok(gif)
+}
+
+/// The sides of a GIF frame, which have to fit in 16 bits
+fn gif_size(width: usize, height: usize) -> io::Result<(u16, u16)> {
+    match (u16::try_from(width), u16::try_from(height)) {
+        (Ok(width), Ok(height)) => Ok((width, height)),
+        _ => Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            format!(
+                "{width} by {height} pixel frames are too large for a GIF, try a smaller --scale"
+            ),
+        )),
+    }
+}
+
+/// The `index`th colour of a 6 by 6 by 6 cube spread evenly over RGB
+fn cube_colour(index: usize) -> Colour {
+    let level = |n: usize| (n % 6 * 51) as u8;
+    Colour::rgb(level(index / 36), level(index / 6), level(index))
+}
+
+fn cube_index(pixel: &[u8]) -> u8 {
+    let level = |channel: u8| (channel as usize + 25) / 51;
+    (level(pixel[0]) * 36 + level(pixel[1]) * 6 + level(pixel[2])) as u8
+}
+
+/// Packs variable-width codes into bytes, least significant bit first
+#[derive(Default)]
+struct BitWriter {
+    bytes: Vec<u8>,
+    buffer: u32,
+    length: u8,
+}
+
+impl BitWriter {
+    fn write(&mut self, code: u16, width: u8) {
+        self.buffer |= u32::from(code) << self.length;
+        self.length += width;
+        while self.length >= 8 {
+            self.bytes.push(self.buffer as u8);
+            self.buffer >>= 8;
+            self.length -= 8;
+        }
+    }
+
+    fn finish(mut self) -> Vec<u8> {
+        if self.length > 0 {
+            self.bytes.push(self.buffer as u8);
+        }
+        self.bytes
+    }
+}
+
+/// Compresses palette indices of at most `bits` bits with the variable-width LZW that GIF uses
+fn lzw(indices: &[u8], bits: u8) -> Vec<u8> {
+    const MAX_CODES: u16 = 4096;
+    let clear = 1u16 << bits;
+    let end = clear + 1;
+    let mut writer = BitWriter::default();
+    let mut width = bits + 1;
+    let mut table = HashMap::new();
+    let mut next = end + 1;
+
+    writer.write(clear, width);
+    let mut indices = indices.iter();
+    if let Some(&first) = indices.next() {
+        let mut prefix = u16::from(first);
+        for &index in indices {
+            if let Some(&code) = table.get(&(prefix, index)) {
+                prefix = code;
+                continue;
+            }
+            writer.write(prefix, width);
+            if next < MAX_CODES {
+                table.insert((prefix, index), next);
+                next += 1;
+                // The decoder is one code behind, so it widens only once it has used this width up
+                if next > 1 << width && width < 12 {
+                    width += 1;
+                }
+            } else {
+                writer.write(clear, width);
+                table.clear();
+                next = end + 1;
+                width = bits + 1;
+            }
+            prefix = u16::from(index);
+        }
+        writer.write(prefix, width);
+    }
+    writer.write(end, width);
+    writer.finish()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::grid::Grid;
+
+    /// A plain GIF LZW decoder, to check the encoder against
+    fn unlzw(data: &[u8], bits: u8) -> Vec<u8> {
+        let clear = 1usize << bits;
+        let mut width = bits + 1;
+        let mut table: Vec<Vec<u8>> = Vec::new();
+        let reset = |table: &mut Vec<Vec<u8>>| {
+            *table = (0..clear).map(|index| vec![index as u8]).collect();
+            table.extend([Vec::new(), Vec::new()]);
+        };
+        reset(&mut table);
+        let (mut position, mut previous, mut output) = (0, None::<Vec<u8>>, Vec::new());
+        loop {
+            let code = (0..width as usize).fold(0, |code, bit| {
+                let at = position + bit;
+                code | (((data[at / 8] >> (at % 8)) & 1) as usize) << bit
+            });
+            position += width as usize;
+            if code == clear {
+                reset(&mut table);
+                width = bits + 1;
+                previous = None;
+                continue;
+            }
+            if code == clear + 1 {
+                return output;
+            }
+            let entry = match (table.get(code), &previous) {
+                (Some(entry), _) => entry.clone(),
+                (None, Some(previous)) => [previous.clone(), vec![previous[0]]].concat(),
+                (None, None) => panic!("code {code} isn't in the table"),
+            };
+            if let Some(previous) = previous {
+                if table.len() < 4096 {
+                    table.push([previous, vec![entry[0]]].concat());
+                    if table.len() == 1 << width && width < 12 {
+                        width += 1;
+                    }
+                }
+            }
+            output.extend(&entry);
+            previous = Some(entry);
+        }
+    }
+
+    #[test]
+    fn test_lzw_round_trip() {
+        let repetitive = (0..5000).map(|i| (i / 7 % 3) as u8).collect::<Vec<u8>>();
+        assert_eq!(unlzw(&lzw(&repetitive, 2), 2), repetitive);
+        // Enough variety to fill the code table and force a clear
+        let noisy = (0..40_000u32)
+            .map(|i| (i.wrapping_mul(2_654_435_761) >> 24) as u8)
+            .collect::<Vec<u8>>();
+        assert_eq!(unlzw(&lzw(&noisy, 8), 8), noisy);
+        assert_eq!(unlzw(&lzw(&[], 2), 2), Vec::<u8>::new());
+    }
+
+    #[test]
+    fn test_recorder_skips_and_crops() {
+        let mut recorder = Recorder::new(3).cropped(Bounds {
+            left: 1,
+            top: 0,
+            right: 1,
+            bottom: 0,
+        });
+        let draw = |step: usize| {
+            let glyph = char::from_digit(step as u32, 10).unwrap();
+            Canvas::from_chars(&Grid::new(2, 1, glyph))
+        };
+        for step in 0..8 {
+            recorder.record(|| draw(step));
+        }
+        recorder.finish(|| draw(7));
+        let frames = recorder.frames().iter().map(Canvas::to_text);
+        assert_eq!(frames.collect::<Vec<_>>(), ["0\n", "3\n", "6\n", "7\n"]);
+        assert_eq!(recorder.steps(), 8);
+
+        // Nothing extra when the last step was kept anyway
+        let mut recorder = Recorder::new(2);
+        for step in 0..3 {
+            recorder.record(|| draw(step));
+        }
+        recorder.finish(|| panic!("the last step was already kept"));
+        assert_eq!(recorder.frames().len(), 2);
+    }
+
+    #[test]
+    fn test_gif_layout() {
+        let mut image = Image::new(3, 2);
+        image.set((1, 1), Colour::RED);
+        let gif = to_gif(&[image.clone(), image], 5).unwrap();
+        assert_eq!(&gif[..6], b"GIF89a");
+        assert_eq!(&gif[6..10], &[3, 0, 2, 0]);
+        // Two colours still take the minimum four-entry table
+        assert_eq!(gif[10], 0xf1);
+        let frames = gif
+            .windows(4)
+            .filter(|window| window == &[0x21, 0xf9, 4, 0]);
+        assert_eq!(frames.count(), 2);
+        assert_eq!(gif.last(), Some(&0x3b));
+
+        let busy = Image {
+            width: 300,
+            height: 1,
+            pixels: (0..300)
+                .flat_map(|i| [i as u8, (i / 256) as u8, 0])
+                .collect(),
+        };
+        assert_eq!(to_gif(&[busy], 0).unwrap()[10], 0xf7);
+
+        let wide = to_gif(&[Image::new(65536, 1)], 0).unwrap_err();
+        assert_eq!(wide.kind(), io::ErrorKind::InvalidInput);
+    }
+}
@@ -0,0 +1,124 @@
+use std::{convert::Infallible, fmt, str::FromStr};
+
+use crate::budget::BudgetExceeded;
+
+/// The answer to one part of a puzzle. Days return whatever integer type suits them, or a string
+/// for answers such as `co,de,ka,ta`; both convert into an `Answer` so results can be handled
+/// and compared uniformly
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Answer {
+    Number(i128),
+    Text(String),
+}
+
+/// What solving a part comes to: its answer, or why it has none
+pub type Solved = Result<Answer, SolveError>;
+
+/// Why a part finished without an answer
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum SolveError {
+    /// A long-running loop gave up
+    Budget(BudgetExceeded),
+    /// The input has no answer with the parameters it was given, such as robots starting outside
+    /// a room that was made too small for them
+    NoAnswer(String),
+}
+
+impl fmt::Display for SolveError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            SolveError::Budget(error) => write!(f, "{error}"),
+            SolveError::NoAnswer(reason) => write!(f, "No answer: {reason}"),
+        }
+    }
+}
+
+impl std::error::Error for SolveError {}
+
+impl From<BudgetExceeded> for SolveError {
+    fn from(error: BudgetExceeded) -> SolveError {
+        SolveError::Budget(error)
+    }
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Answer::Number(number) => write!(f, "{number}"),
+            Answer::Text(text) => write!(f, "{text}"),
+        }
+    }
+}
+
+/// Parses an answer as written by `Display`. Only integers in their canonical form are numbers,
+/// so `007` stays text and round-trips unchanged
+impl FromStr for Answer {
+    type Err = Infallible;
+
+    fn from_str(s: &str) -> Result<Answer, Infallible> {
+        Ok(match s.parse::<i128>() {
+            Ok(number) if number.to_string() == s => Answer::Number(number),
+            _ => Answer::Text(s.to_string()),
+        })
+    }
+}
+
+macro_rules! impl_from_integer {
+    ($($integer:ty),*) => {
+        $(
+            impl From<$integer> for Answer {
+                fn from(number: $integer) -> Answer {
+                    Answer::Number(number.into())
+                }
+            }
+        )*
+    };
+}
+
+impl_from_integer!(i32, i64, u32, u64);
+
+impl From<usize> for Answer {
+    fn from(number: usize) -> Answer {
+        Answer::Number(number as i128)
+    }
+}
+
+impl From<String> for Answer {
+    fn from(text: String) -> Answer {
+        Answer::Text(text)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(text: &str) -> Answer {
+        Answer::Text(text.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_and_display() {
+        for text in ["1879048", "-3", "co,de,ka,ta", "2,7,6,5", "007", ""] {
+            assert_eq!(text.parse::<Answer>().unwrap().to_string(), text);
+        }
+        assert_eq!(
+            "426214131924213".parse(),
+            Ok(Answer::Number(426214131924213))
+        );
+        assert_eq!("007".parse(), Ok(Answer::Text(String::from("007"))));
+    }
+
+    #[test]
+    fn test_conversions_compare_equal() {
+        assert_eq!(Answer::from(31u32), Answer::from(31i64));
+        assert_eq!(Answer::from(u64::MAX).to_string(), u64::MAX.to_string());
+        assert_eq!(
+            Answer::from("co,de,ka,ta"),
+            Answer::from(String::from("co,de,ka,ta"))
+        );
+        assert_ne!(Answer::from(31u32), Answer::from("31"));
+    }
+}
@@ -0,0 +1,216 @@
+use std::{
+    collections::HashMap,
+    fmt, fs, io,
+    path::{Path, PathBuf},
+};
+
+use crate::{answer::Answer, Part};
+
+/// Name of the known-answer registry inside the data directory
+pub const ANSWERS_FILE: &str = "answers.txt";
+
+/// Expected answers, keyed by day, part and input set.
+///
+/// The registry is a plain text file with one answer per line, in the form
+/// `<day> <part> <set> <answer>`, e.g. `07 2 default 426214131924213`. An answer that was only
+/// recorded from the solver's own output, and never confirmed as the accepted one, is followed by
+/// `recorded`. Blank lines and lines starting with `#` are ignored.
+#[derive(Debug, Default)]
+pub struct Answers {
+    entries: HashMap<(u8, Part, String), Expected>,
+}
+
+/// An answer in the registry, and whether it is only a recording of earlier output
+#[derive(Clone, Debug, PartialEq)]
+pub struct Expected {
+    pub answer: Answer,
+    pub recorded: bool,
+}
+
+#[derive(Debug)]
+pub enum AnswersError {
+    Unreadable(PathBuf, io::Error),
+    Malformed { line: usize, text: String },
+}
+
+impl fmt::Display for AnswersError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            AnswersError::Unreadable(path, error) => {
+                write!(f, "Failed to read {}: {error}", path.display())
+            }
+            AnswersError::Malformed { line, text } => {
+                write!(f, "Malformed answer on line {line}: {text}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for AnswersError {}
+
+/// Outcome of checking a computed answer against the registry
+#[derive(Debug, PartialEq)]
+pub enum Verdict {
+    Pass,
+    /// The answer is the same as one recorded from earlier output, which may itself be wrong
+    Unchanged,
+    Fail {
+        expected: Answer,
+    },
+    Missing,
+}
+
+impl Answers {
+    /// Reads the registry at `path`. A missing file is an empty registry rather than an error,
+    /// so that `verify` can still report which answers are missing
+    pub fn load(path: &Path) -> Result<Answers, AnswersError> {
+        match fs::read_to_string(path) {
+            Ok(text) => Answers::parse(&text),
+            Err(error) if error.kind() == io::ErrorKind::NotFound => Ok(Answers::default()),
+            Err(error) => Err(AnswersError::Unreadable(path.to_path_buf(), error)),
+        }
+    }
+
+    pub fn parse(text: &str) -> Result<Answers, AnswersError> {
+        let mut answers = Answers::default();
+        for (i, line) in text.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let malformed = || AnswersError::Malformed {
+                line: i + 1,
+                text: line.to_string(),
+            };
+            let fields = line.split_whitespace().collect::<Vec<&str>>();
+            let (day, part, set, answer, recorded) = match fields[..] {
+                [day, part, set, answer] => (day, part, set, answer, false),
+                [day, part, set, answer, "recorded"] => (day, part, set, answer, true),
+                _ => return Err(malformed()),
+            };
+            let day = day.parse::<u8>().map_err(|_| malformed())?;
+            let part = match part {
+                "1" => Part::One,
+                "2" => Part::Two,
+                _ => return Err(malformed()),
+            };
+            let Ok(answer) = answer.parse();
+            answers.insert(day, part, set, Expected { answer, recorded });
+        }
+        Ok(answers)
+    }
+
+    pub fn insert(&mut self, day: u8, part: Part, set: &str, expected: Expected) {
+        self.entries.insert((day, part, set.to_string()), expected);
+    }
+
+    pub fn get(&self, day: u8, part: Part, set: &str) -> Option<&Expected> {
+        self.entries.get(&(day, part, set.to_string()))
+    }
+
+    pub fn check(&self, day: u8, part: Part, set: &str, actual: &Answer) -> Verdict {
+        match self.get(day, part, set) {
+            None => Verdict::Missing,
+            Some(expected) if expected.answer != *actual => Verdict::Fail {
+                expected: expected.answer.clone(),
+            },
+            Some(expected) if expected.recorded => Verdict::Unchanged,
+            Some(_) => Verdict::Pass,
+        }
+    }
+}
+
+/// Describes how a wrong answer differs from the expected one: the signed difference for numbers,
+/// otherwise the first character position at which the two disagree
+pub fn describe_difference(expected: &Answer, actual: &Answer) -> String {
+    if let (Answer::Number(expected), Answer::Number(actual)) = (expected, actual) {
+        return format!("{:+}", actual - expected);
+    }
+    let (expected, actual) = (expected.to_string(), actual.to_string());
+    let position = expected
+        .chars()
+        .zip(actual.chars())
+        .position(|(e, a)| e != a)
+        .unwrap_or(std::cmp::min(
+            expected.chars().count(),
+            actual.chars().count(),
+        ));
+    format!("differs at character {}", position + 1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const REGISTRY: &str = "# day part set answer
+01 1 default 11
+
+23 2 default co,de,ka,ta
+23 2 alice ab,cd
+24 1 default 7 recorded
+";
+
+    #[test]
+    fn test_parse_and_check() {
+        let answers = Answers::parse(REGISTRY).unwrap();
+        assert_eq!(
+            answers.check(1, Part::One, "default", &Answer::from(11u32)),
+            Verdict::Pass
+        );
+        assert_eq!(
+            answers.check(1, Part::One, "default", &Answer::from(12u32)),
+            Verdict::Fail {
+                expected: Answer::from(11u32)
+            }
+        );
+        assert_eq!(
+            answers.check(1, Part::Two, "default", &Answer::from(31u32)),
+            Verdict::Missing
+        );
+        assert_eq!(
+            answers
+                .get(23, Part::Two, "alice")
+                .map(|expected| &expected.answer),
+            Some(&Answer::from("ab,cd"))
+        );
+        assert_eq!(
+            answers.check(24, Part::One, "default", &Answer::from(7u32)),
+            Verdict::Unchanged
+        );
+        assert_eq!(
+            answers.check(24, Part::One, "default", &Answer::from(8u32)),
+            Verdict::Fail {
+                expected: Answer::from(7u32)
+            }
+        );
+        assert_eq!(answers.get(23, Part::Two, "bob"), None);
+    }
+
+    #[test]
+    fn test_malformed_line() {
+        let error = Answers::parse("01 1 default 11\n01 3 default 4").unwrap_err();
+        assert!(matches!(error, AnswersError::Malformed { line: 2, .. }));
+        assert!(Answers::parse("01 1 11").is_err());
+        assert!(Answers::parse("01 1 default 11 guessed").is_err());
+    }
+
+    #[test]
+    fn test_describe_difference() {
+        assert_eq!(
+            describe_difference(&Answer::from(11u32), &Answer::from(8u32)),
+            "-3"
+        );
+        assert_eq!(
+            describe_difference(&Answer::from(11u32), &Answer::from(20u32)),
+            "+9"
+        );
+        assert_eq!(
+            describe_difference(&Answer::from("co,de,ka,ta"), &Answer::from("co,de,ta")),
+            "differs at character 7"
+        );
+        assert_eq!(
+            describe_difference(&Answer::from("abc"), &Answer::from("abcd")),
+            "differs at character 4"
+        );
+    }
+}
@@ -0,0 +1,219 @@
+use std::{
+    fmt,
+    hint::black_box,
+    time::{Duration, Instant},
+};
+
+use crate::{params::Settings, table::Table, Day, Part};
+
+/// Number of times each stage is run when no `--iterations` is given
+pub const DEFAULT_ITERATIONS: usize = 10;
+
+/// The piece of a day's solution that a measurement covers. Parts are timed end to end, so
+/// their times include any parsing they do themselves
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Stage {
+    Parse,
+    Part(Part),
+}
+
+impl fmt::Display for Stage {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Stage::Parse => write!(f, "parse"),
+            Stage::Part(part) => write!(f, "part {part}"),
+        }
+    }
+}
+
+/// How benchmark results are printed
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Format {
+    Table,
+    Json,
+}
+
+impl std::str::FromStr for Format {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Format, String> {
+        match s {
+            "table" => Ok(Format::Table),
+            "json" => Ok(Format::Json),
+            other => Err(format!("Unrecognised format {other}")),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Timings {
+    pub min: Duration,
+    pub median: Duration,
+    pub max: Duration,
+}
+
+impl Timings {
+    /// Summarises a set of samples; with an even number of samples the upper median is used
+    pub fn from_samples(samples: &mut [Duration]) -> Option<Timings> {
+        samples.sort();
+        Some(Timings {
+            min: *samples.first()?,
+            median: samples[samples.len() / 2],
+            max: *samples.last()?,
+        })
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Measurement {
+    pub day: u8,
+    pub stage: Stage,
+    pub iterations: usize,
+    pub timings: Timings,
+}
+
+fn run_stage(day: &Day, input: &str, stage: Stage, settings: &Settings) {
+    match stage {
+        Stage::Parse => {
+            black_box(day.parse(black_box(input)).ok());
+        }
+        Stage::Part(part) => {
+            // Settings are checked before timing, so only a part without an answer can fail here
+            black_box(day.solve_with(black_box(input), part, settings).ok());
+        }
+    }
+}
+
+/// Runs one stage of a day `iterations` times and summarises how long each run took. The
+/// settings must already have been checked with `Day::check_settings`
+pub fn time_stage(
+    day: &Day,
+    input: &str,
+    stage: Stage,
+    iterations: usize,
+    settings: &Settings,
+) -> Measurement {
+    let mut samples = (0..iterations.max(1))
+        .map(|_| {
+            let start = Instant::now();
+            run_stage(day, input, stage, settings);
+            start.elapsed()
+        })
+        .collect::<Vec<Duration>>();
+    Measurement {
+        day: day.number,
+        stage,
+        iterations: samples.len(),
+        timings: Timings::from_samples(&mut samples).expect("at least one sample is taken"),
+    }
+}
+
+/// Times parsing and then each requested part of a day
+pub fn bench_day(
+    day: &Day,
+    input: &str,
+    parts: &[Part],
+    iterations: usize,
+    settings: &Settings,
+) -> Vec<Measurement> {
+    let stages = std::iter::once(Stage::Parse).chain(parts.iter().map(|&part| Stage::Part(part)));
+    stages
+        .map(|stage| time_stage(day, input, stage, iterations, settings))
+        .collect()
+}
+
+pub fn to_table(measurements: &[Measurement]) -> Table {
+    let mut table = Table::new(&["Day", "Stage", "Runs", "Min", "Median", "Max"]);
+    for measurement in measurements {
+        let timings = &measurement.timings;
+        table.add_row(vec![
+            format!("{:02}", measurement.day),
+            measurement.stage.to_string(),
+            measurement.iterations.to_string(),
+            format!("{:.2?}", timings.min),
+            format!("{:.2?}", timings.median),
+            format!("{:.2?}", timings.max),
+        ]);
+    }
+    table
+}
+
+/// Renders the measurements as a JSON array with one object per stage and times in nanoseconds,
+/// so that results can be stored and compared between commits
+pub fn to_json(measurements: &[Measurement]) -> String {
+    let objects = measurements
+        .iter()
+        .map(|measurement| {
+            let timings = &measurement.timings;
+            format!(
+                "  {{\"day\": {}, \"stage\": \"{}\", \"iterations\": {}, \"min_ns\": {}, \"median_ns\": {}, \"max_ns\": {}}}",
+                measurement.day,
+                measurement.stage,
+                measurement.iterations,
+                timings.min.as_nanos(),
+                timings.median.as_nanos(),
+                timings.max.as_nanos(),
+            )
+        })
+        .collect::<Vec<String>>();
+    if objects.is_empty() {
+        return String::from("[]\n");
+    }
+    format!("[\n{}\n]\n", objects.join(",\n"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::get_day;
+
+    #[test]
+    fn test_timings_from_samples() {
+        let mut samples = [5, 1, 3, 2].map(Duration::from_millis);
+        let timings = Timings::from_samples(&mut samples).unwrap();
+        assert_eq!(timings.min, Duration::from_millis(1));
+        assert_eq!(timings.median, Duration::from_millis(3));
+        assert_eq!(timings.max, Duration::from_millis(5));
+        assert_eq!(Timings::from_samples(&mut []), None);
+    }
+
+    #[test]
+    fn test_bench_day_covers_every_stage() {
+        let day = get_day(1).unwrap();
+        let measurements = bench_day(
+            day,
+            "3   4\n4   3\n",
+            &[Part::One, Part::Two],
+            3,
+            &Settings::default(),
+        );
+        let stages = measurements.iter().map(|m| m.stage).collect::<Vec<_>>();
+        assert_eq!(
+            stages,
+            vec![Stage::Parse, Stage::Part(Part::One), Stage::Part(Part::Two)]
+        );
+        assert!(measurements.iter().all(|m| m.iterations == 3));
+    }
+
+    #[test]
+    fn test_json_output() {
+        let measurement = Measurement {
+            day: 6,
+            stage: Stage::Part(Part::Two),
+            iterations: 10,
+            timings: Timings {
+                min: Duration::from_nanos(1),
+                median: Duration::from_nanos(20),
+                max: Duration::from_nanos(300),
+            },
+        };
+        assert_eq!(
+            to_json(&[measurement]),
+            "[
+  {\"day\": 6, \"stage\": \"part 2\", \"iterations\": 10, \"min_ns\": 1, \"median_ns\": 20, \"max_ns\": 300}
+]
+"
+        );
+        assert_eq!(to_json(&[]), "[]\n");
+    }
+}
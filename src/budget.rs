@@ -0,0 +1,64 @@
+use std::fmt;
+
+/// How many steps a long-running loop may take before giving up, so that a loop which never
+/// finishes is reported rather than cut short with whatever answer it had reached
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Budget {
+    limit: u64,
+    spent: u64,
+}
+
+/// A loop ran out of budget after taking `steps` steps
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct BudgetExceeded {
+    pub steps: u64,
+}
+
+impl fmt::Display for BudgetExceeded {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "Gave up after {} steps, raise --budget to keep going",
+            self.steps
+        )
+    }
+}
+
+impl std::error::Error for BudgetExceeded {}
+
+impl Budget {
+    pub fn new(limit: u64) -> Budget {
+        Budget { limit, spent: 0 }
+    }
+
+    /// Takes one step, failing once the limit has been spent
+    pub fn spend(&mut self) -> Result<(), BudgetExceeded> {
+        if self.spent == self.limit {
+            return Err(BudgetExceeded { steps: self.spent });
+        }
+        self.spent += 1;
+        Ok(())
+    }
+
+    pub fn spent(&self) -> u64 {
+        self.spent
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_spend() {
+        let mut budget = Budget::new(2);
+        assert_eq!(budget.spend(), Ok(()));
+        assert_eq!(budget.spend(), Ok(()));
+        assert_eq!(budget.spend(), Err(BudgetExceeded { steps: 2 }));
+        assert_eq!(budget.spent(), 2);
+        assert_eq!(
+            Budget::new(0).spend().unwrap_err().to_string(),
+            "Gave up after 0 steps, raise --budget to keep going"
+        );
+    }
+}
@@ -0,0 +1,182 @@
+use std::{collections::HashMap, hash::Hash};
+
+/// Where a deterministic simulation starts repeating itself
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Cycle<S> {
+    /// How many steps it takes to reach the cycle from the start
+    pub start: usize,
+    pub length: usize,
+    /// The first state to be visited a second time, which is the first state on the cycle
+    pub state: S,
+}
+
+/// Steps `state` forward `steps` times along a path already known not to halt
+fn advance<S, F>(mut state: S, steps: usize, step: &mut F) -> S
+where
+    F: FnMut(&S) -> Option<S>,
+{
+    for _ in 0..steps {
+        state = step(&state).expect("a cycling simulation should not halt");
+    }
+    state
+}
+
+/// Finds where a cycle of a known `length` begins, by walking two states `length` steps apart from
+/// the start until they meet
+fn locate<S, F>(start: S, length: usize, step: &mut F) -> Cycle<S>
+where
+    S: Clone + PartialEq,
+    F: FnMut(&S) -> Option<S>,
+{
+    let mut tortoise = start.clone();
+    let mut hare = advance(start, length, step);
+    let mut offset = 0;
+    while tortoise != hare {
+        tortoise = advance(tortoise, 1, step);
+        hare = advance(hare, 1, step);
+        offset += 1;
+    }
+    Cycle {
+        start: offset,
+        length,
+        state: tortoise,
+    }
+}
+
+/// Brent's cycle finder, which keeps only two states however long the simulation runs. `step`
+/// gives the state after a state, or `None` if the simulation halts there, in which case there
+/// is no cycle. It must be deterministic, as it is run over the same states more than once
+pub fn brent<S, F>(start: S, mut step: F) -> Option<Cycle<S>>
+where
+    S: Clone + PartialEq,
+    F: FnMut(&S) -> Option<S>,
+{
+    let (mut power, mut length) = (1, 1);
+    let mut tortoise = start.clone();
+    let mut hare = step(&start)?;
+    while tortoise != hare {
+        if power == length {
+            tortoise = hare.clone();
+            power *= 2;
+            length = 0;
+        }
+        hare = step(&hare)?;
+        length += 1;
+    }
+    Some(locate(start, length, &mut step))
+}
+
+/// Floyd's tortoise and hare cycle finder. Like [`brent`] it keeps only two states, but it usually
+/// takes more steps
+pub fn floyd<S, F>(start: S, mut step: F) -> Option<Cycle<S>>
+where
+    S: Clone + PartialEq,
+    F: FnMut(&S) -> Option<S>,
+{
+    let mut tortoise = step(&start)?;
+    let mut hare = step(&tortoise)?;
+    while tortoise != hare {
+        tortoise = advance(tortoise, 1, &mut step);
+        hare = step(&hare)?;
+        hare = step(&hare)?;
+    }
+    let mut length = 1;
+    let mut lap = advance(tortoise.clone(), 1, &mut step);
+    while lap != tortoise {
+        lap = advance(lap, 1, &mut step);
+        length += 1;
+    }
+    Some(locate(start, length, &mut step))
+}
+
+/// Every state a simulation passed through, in order, and the cycle it fell into if it did not
+/// halt. The last state is the one before the repeat
+#[derive(Clone, Debug)]
+pub struct History<S> {
+    pub states: Vec<S>,
+    pub cycle: Option<Cycle<S>>,
+}
+
+/// Runs a simulation until it halts or repeats a state, remembering every state in a hash map. It
+/// steps each state only once, so suits simulations whose states are needed afterwards anyway
+pub fn record<S, F>(start: S, mut step: F) -> History<S>
+where
+    S: Clone + Eq + Hash,
+    F: FnMut(&S) -> Option<S>,
+{
+    let mut seen = HashMap::from([(start.clone(), 0)]);
+    let mut states = vec![start];
+    while let Some(next) = step(states.last().unwrap()) {
+        if let Some(&first) = seen.get(&next) {
+            let cycle = Cycle {
+                start: first,
+                length: states.len() - first,
+                state: next,
+            };
+            return History {
+                states,
+                cycle: Some(cycle),
+            };
+        }
+        seen.insert(next.clone(), states.len());
+        states.push(next);
+    }
+    History {
+        states,
+        cycle: None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // 3 -> 10 -> 5 -> 16 -> 8 -> 4 -> 2 -> 1 -> 4 -> ...
+    fn collatz(&n: &u64) -> Option<u64> {
+        Some(if n % 2 == 0 { n / 2 } else { 3 * n + 1 })
+    }
+
+    #[test]
+    fn test_finders_agree() {
+        let expected = Cycle {
+            start: 5,
+            length: 3,
+            state: 4,
+        };
+        assert_eq!(brent(3, collatz), Some(expected.clone()));
+        assert_eq!(floyd(3, collatz), Some(expected.clone()));
+        let history = record(3, collatz);
+        assert_eq!(history.cycle, Some(expected));
+        assert_eq!(history.states, vec![3, 10, 5, 16, 8, 4, 2, 1]);
+
+        // A cycle straight away
+        let immediate = Cycle {
+            start: 0,
+            length: 1,
+            state: 7,
+        };
+        assert_eq!(brent(7, |&n| Some(n)), Some(immediate.clone()));
+        assert_eq!(floyd(7, |&n| Some(n)), Some(immediate));
+    }
+
+    #[test]
+    fn test_halting_simulation_has_no_cycle() {
+        let countdown = |&n: &u32| n.checked_sub(1);
+        assert_eq!(brent(10, countdown), None);
+        assert_eq!(floyd(10, countdown), None);
+        let history = record(10, countdown);
+        assert_eq!(history.cycle, None);
+        assert_eq!(history.states.len(), 11);
+    }
+
+    #[test]
+    fn test_long_cycle() {
+        // Multiplying by 3 modulo a prime p gets back to 1 after some divisor of p - 1 steps
+        let step = |&n: &u64| Some(n * 3 % 1_000_003);
+        let cycle = brent(1, step).unwrap();
+        assert_eq!((cycle.start, cycle.state), (0, 1));
+        assert_eq!(cycle.length, floyd(1, step).unwrap().length);
+        assert_eq!(cycle.length, record(1, step).cycle.unwrap().length);
+        assert_eq!(1_000_002 % cycle.length, 0);
+    }
+}
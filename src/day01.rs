@@ -0,0 +1,117 @@
+//! Day 1: Historian Hysteria
+
+use crate::{
+    answer::Solved,
+    parse::{expect_valid, non_blank_lines, ErrorKind, ParseError},
+    Solution,
+};
+use std::{collections::HashMap, hint::black_box};
+
+pub fn sort_input_arrays(content: &str) -> Result<(Vec<u32>, Vec<u32>), ParseError> {
+    let mut first: Vec<u32> = Vec::new();
+    let mut second: Vec<u32> = Vec::new();
+
+    for line in non_blank_lines(content) {
+        match line.integers::<u32>()?[..] {
+            [left, right] => {
+                first.push(left);
+                second.push(right);
+            }
+            _ => {
+                let expected = String::from("two location IDs");
+                return Err(line.error(ErrorKind::Expected(expected)));
+            }
+        }
+    }
+
+    // Sort both arrays
+    first.sort();
+    second.sort();
+
+    Ok((first, second))
+}
+
+pub fn part_one(content: &str) -> u32 {
+    let (first, second) = expect_valid(sort_input_arrays(content));
+    let mut sum_of_differences: u32 = 0;
+    for i in 0..first.len() {
+        sum_of_differences += first[i].abs_diff(second[i]);
+    }
+
+    sum_of_differences
+}
+
+pub fn part_two(content: &str) -> u32 {
+    let (first, second) = expect_valid(sort_input_arrays(content));
+
+    // Construct hash maps of both arrays
+    let mut first_map = HashMap::new();
+    let mut second_map = HashMap::new();
+    for i in first {
+        let count = first_map.entry(i).or_insert(0);
+        *count += 1;
+    }
+    for i in second {
+        let count = second_map.entry(i).or_insert(0);
+        *count += 1;
+    }
+
+    let mut similarity_score = 0;
+    for (key, value) in &first_map {
+        if second_map.contains_key(key) {
+            similarity_score += *key * *value * *second_map.entry(*key).or_insert(0);
+        }
+    }
+
+    similarity_score
+}
+
+pub struct Solver;
+
+impl Solution for Solver {
+    type Params = ();
+
+    fn parse(input: &str) -> Result<(), ParseError> {
+        black_box(sort_input_arrays(input))?;
+        Ok(())
+    }
+
+    fn part_one(input: &str, _: &()) -> Solved {
+        Ok(part_one(input).into())
+    }
+
+    fn part_two(input: &str, _: &()) -> Solved {
+        Ok(part_two(input).into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let test_data = "3   4
+        4   3
+        2   5
+        1   3
+        3   9
+        3   3";
+
+        let result = part_one(test_data);
+        assert_eq!(result, 11);
+    }
+
+    #[test]
+    fn test_part_two() {
+        let test_data = "3   4
+        4   3
+        2   5
+        1   3
+        3   9
+        3   3";
+
+        let result = part_two(test_data);
+        assert_eq!(result, 31);
+    }
+}
@@ -0,0 +1,196 @@
+//! Day 2: Red-Nosed Reports
+
+use crate::{
+    answer::Solved,
+    parse::{expect_valid, non_blank_lines, ParseError},
+    Solution,
+};
+use std::hint::black_box;
+
+pub fn parse_input(data: &str) -> Result<Vec<Vec<u32>>, ParseError> {
+    non_blank_lines(data).map(|line| line.integers()).collect()
+}
+
+pub fn count_safe_reports(reports: &[Vec<u32>]) -> u32 {
+    let mut number_safe = 0;
+    'outer: for report in reports {
+        if report.is_empty() {
+            continue;
+        };
+        let is_increasing = report[1] > report[0];
+        let mut prev = report[0];
+        for level in &report[1..] {
+            let diff = level.abs_diff(prev);
+            let has_increased = *level > prev;
+            prev = *level;
+            if is_increasing != has_increased {
+                continue 'outer;
+            }
+            if diff == 0 || diff > 3 {
+                continue 'outer;
+            }
+        }
+        number_safe += 1;
+    }
+    number_safe
+}
+
+pub fn count_dampened_safe_reports(reports: &[Vec<u32>]) -> u32 {
+    let mut number_safe = 0;
+    'outer: for report in reports {
+        if report.len() < 3 {
+            if !report.is_empty() {
+                number_safe += 1;
+            }
+            continue;
+        };
+        let direction_matters = report.len() > 3; // Direction does not matter when the report is
+                                                  // of length 3. We can remove any node and still have direction respected.
+
+        fn delta(report: &[u32], i: usize, problem_index: Option<usize>) -> i32 {
+            match problem_index {
+                Some(p) => {
+                    if i == p {
+                        report[i + 1] as i32 - report[i - 1] as i32
+                    } else if i - 1 == p {
+                        report[i] as i32 - report[i - 2] as i32
+                    } else {
+                        report[i] as i32 - report[i - 1] as i32
+                    }
+                }
+                None => report[i] as i32 - report[i - 1] as i32,
+            }
+        }
+        fn is_delta_increasing(report: &[u32], i: usize, problem_index: Option<usize>) -> bool {
+            delta(report, i, problem_index) > 0
+        }
+        fn is_delta_invalid(
+            report: &[u32],
+            i: usize,
+            direction: Option<bool>,
+            problem_index: Option<usize>,
+        ) -> bool {
+            let this_delta = delta(report, i, problem_index);
+            let direction_factor = match direction {
+                Some(d) => d == is_delta_increasing(report, i, problem_index),
+                None => true,
+            };
+            !direction_factor || this_delta == 0 || this_delta.abs() > 3
+        }
+
+        let mut is_increasing = false;
+        let mut problem_index: Option<usize> = None;
+        if direction_matters {
+            if is_delta_increasing(report, 1, None) == is_delta_increasing(report, 2, None) {
+                is_increasing = is_delta_increasing(report, 1, None);
+            } else {
+                is_increasing = is_delta_increasing(report, 3, None);
+            }
+        }
+        let mut direction_option = None;
+        if direction_matters {
+            direction_option = Some(is_increasing);
+        }
+        for i in 1..report.len() {
+            if is_delta_invalid(report, i, direction_option, problem_index) {
+                if problem_index.is_some() {
+                    continue 'outer; // This report is invalid; second mistake
+                }
+                // Try right delta first as left is known to be valid
+                problem_index = Some(i);
+                if i + 1 < report.len()
+                    && is_delta_invalid(report, i + 1, direction_option, problem_index)
+                {
+                    // Still invalid - try left
+                    problem_index = Some(i - 1);
+                    if i > 1 && is_delta_invalid(report, i - 1, direction_option, problem_index) {
+                        continue 'outer; // Unfixable problem
+                    }
+                }
+            }
+        }
+        number_safe += 1;
+    }
+    number_safe
+}
+
+pub fn part_one(input: &str) -> u32 {
+    count_safe_reports(&expect_valid(parse_input(input)))
+}
+
+pub fn part_two(input: &str) -> u32 {
+    count_dampened_safe_reports(&expect_valid(parse_input(input)))
+}
+
+pub struct Solver;
+
+impl Solution for Solver {
+    type Params = ();
+
+    fn parse(input: &str) -> Result<(), ParseError> {
+        black_box(parse_input(input))?;
+        Ok(())
+    }
+
+    fn part_one(input: &str, _: &()) -> Solved {
+        Ok(part_one(input).into())
+    }
+
+    fn part_two(input: &str, _: &()) -> Solved {
+        Ok(part_two(input).into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TEST_DATA: &str = "7 6 4 2 1
+    1 2 7 8 9
+    9 7 6 2 1
+    1 3 2 4 5
+    8 6 4 4 1
+    1 3 6 7 9";
+
+    #[test]
+    fn test_part_one() {
+        let input = parse_input(TEST_DATA).unwrap();
+        let result = count_safe_reports(&input);
+        assert_eq!(result, 2);
+    }
+
+    #[test]
+    fn test_part_two_basic_remove_second() {
+        let input = parse_input("1 0 4 5").unwrap();
+        let result = count_dampened_safe_reports(&input);
+        assert_eq!(result, 1);
+    }
+
+    #[test]
+    fn test_part_two_basic_remove_third() {
+        let input = parse_input("3 2 6 1").unwrap();
+        let result = count_dampened_safe_reports(&input);
+        assert_eq!(result, 1);
+    }
+
+    #[test]
+    fn test_part_two_basic_remove_first() {
+        let input = parse_input("0 4 6 8").unwrap();
+        let result = count_dampened_safe_reports(&input);
+        assert_eq!(result, 1);
+    }
+
+    #[test]
+    fn test_part_two_basic_remove_last() {
+        let input = parse_input("1 2 3 4 5 6 1").unwrap();
+        let result = count_dampened_safe_reports(&input);
+        assert_eq!(result, 1);
+    }
+
+    #[test]
+    fn test_part_two_sample() {
+        let input = parse_input(TEST_DATA).unwrap();
+        let result = count_dampened_safe_reports(&input);
+        assert_eq!(result, 4);
+    }
+}
@@ -0,0 +1,71 @@
+//! Day 3: Mull It Over
+
+use crate::{answer::Solved, Solution};
+use regex::Regex;
+
+pub fn part_one(instructions: &str) -> i32 {
+    let re = Regex::new(r"mul\((\d+),(\d+)\)").unwrap();
+
+    let mut sum = 0;
+    for (_, [left, right]) in re.captures_iter(instructions).map(|c| c.extract()) {
+        let left = left.parse::<i32>().expect("Not a number");
+        let right = right.parse::<i32>().expect("Not a number");
+        sum += left * right;
+    }
+    sum
+}
+
+pub fn part_two(instructions: &str) -> i32 {
+    let re = Regex::new(r"(^|do\(\))(?s).*?(don't\(\)|$)").unwrap();
+    let mut sum = 0;
+
+    for capture in re.captures_iter(instructions) {
+        sum += part_one(&capture[0]);
+    }
+
+    sum
+}
+
+pub struct Solver;
+
+impl Solution for Solver {
+    type Params = ();
+
+    fn part_one(input: &str, _: &()) -> Solved {
+        Ok(part_one(input).into())
+    }
+
+    fn part_two(input: &str, _: &()) -> Solved {
+        Ok(part_two(input).into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one_given() {
+        let instructions =
+            String::from("xmul(2,4)%&mul[3,7]!@^do_not_mul(5,5)+mul(32,64]then(mul(11,8)mul(8,5))");
+        let result = part_one(&instructions);
+        assert_eq!(result, 161);
+    }
+
+    #[test]
+    fn test_part_two_given() {
+        let instructions = String::from(
+            "xmul(2,4)&mul[3,7]!^don't()_mul(5,5)+mul(32,64](mul(11,8)undo()?mul(8,5))",
+        );
+        let result = part_two(&instructions);
+        assert_eq!(result, 48);
+    }
+    #[test]
+    fn test_part_two_newlines() {
+        let instructions = String::from(
+            "xmul(2,4)&mul[3,7]!^don't()_mul(5,5)+mul(32,64](mul(11,8)undo()?\nmul(8,5))",
+        );
+        let result = part_two(&instructions);
+        assert_eq!(result, 48);
+    }
+}
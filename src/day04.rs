@@ -0,0 +1,138 @@
+//! Day 4: Ceres Search
+
+use crate::{
+    answer::Solved,
+    grid::{Grid, Position, SURROUNDING},
+    parse::{char_grid, expect_valid, non_blank_lines, ParseError},
+    Solution,
+};
+use std::hint::black_box;
+
+pub fn parse_input(data: &str) -> Result<Grid<char>, ParseError> {
+    char_grid(non_blank_lines(data))
+}
+
+/// Whether `word` is spelled out from `start` going in the direction of `step`
+fn spells(grid: &Grid<char>, start: Position, step: (isize, isize), word: &str) -> bool {
+    let mut position = Some(start);
+    for letter in word.chars() {
+        match position {
+            Some(p) if grid[p] == letter => position = grid.offset(p, step),
+            _ => return false,
+        }
+    }
+    true
+}
+
+pub fn part_one(data: &str) -> u32 {
+    let grid = expect_valid(parse_input(data));
+    let mut matches: u32 = 0;
+    for start in grid.find_all(&'X') {
+        for step in SURROUNDING {
+            if spells(&grid, start, step, "XMAS") {
+                matches += 1;
+            }
+        }
+    }
+    matches
+}
+
+pub fn part_two(data: &str) -> u32 {
+    let grid = expect_valid(parse_input(data));
+    let mut matches: u32 = 0;
+    for centre in grid.find_all(&'A') {
+        // Each diagonal through the centre has to read MAS one way or the other
+        let is_mas = |(dx, dy): (isize, isize)| {
+            let Some(start) = grid.offset(centre, (-dx, -dy)) else {
+                return false;
+            };
+            spells(&grid, start, (dx, dy), "MAS") || spells(&grid, start, (dx, dy), "SAM")
+        };
+        if is_mas((1, 1)) && is_mas((1, -1)) {
+            matches += 1;
+        }
+    }
+    matches
+}
+
+pub struct Solver;
+
+impl Solution for Solver {
+    type Params = ();
+
+    fn parse(input: &str) -> Result<(), ParseError> {
+        black_box(parse_input(input))?;
+        Ok(())
+    }
+
+    fn part_one(input: &str, _: &()) -> Solved {
+        Ok(part_one(input).into())
+    }
+
+    fn part_two(input: &str, _: &()) -> Solved {
+        Ok(part_two(input).into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one_given() {
+        let data = "MMMSXXMASM
+        MSAMXMSMSA
+        AMXSXMAAMM
+        MSAMASMSMX
+        XMASAMXAMM
+        XXAMMXXAMA
+        SMSMSASXSS
+        SAXAMASAAA
+        MAMMMXMMMM
+        MXMXAXMASX";
+
+        let result = part_one(data);
+        assert_eq!(result, 18);
+    }
+
+    #[test]
+    fn test_part_one_backwards() {
+        let data = "SAMX";
+
+        let result = part_one(data);
+        assert_eq!(result, 1);
+    }
+
+    #[test]
+    fn test_part_one_downwards() {
+        let data = "X\nM\nA\nS";
+
+        let result = part_one(data);
+        assert_eq!(result, 1);
+    }
+
+    #[test]
+    fn test_part_one_diagonals() {
+        let data = "XWWS\nWMAW\nWMAW\nXWWS";
+
+        let result = part_one(data);
+        assert_eq!(result, 2);
+    }
+
+    #[test]
+    fn test_part_two_given() {
+        let data = "MMMSXXMASM
+        MSAMXMSMSA
+        AMXSXMAAMM
+        MSAMASMSMX
+        XMASAMXAMM
+        XXAMMXXAMA
+        SMSMSASXSS
+        SAXAMASAAA
+        MAMMMXMMMM
+        MXMXAXMASX";
+
+        let result = part_two(data);
+        assert_eq!(result, 9);
+    }
+}
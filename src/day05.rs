@@ -0,0 +1,231 @@
+//! Day 5: Print Queue
+
+use crate::{
+    answer::Solved,
+    budget::{Budget, BudgetExceeded},
+    params::{ParamError, Parameters, Settings},
+    parse::{expect_valid, sections_of, ParseError},
+    Solution,
+};
+use std::{collections::HashMap, hint::black_box};
+
+pub fn build_ordering_ruleset(rules: &[(i32, i32)]) -> HashMap<i32, Vec<i32>> {
+    // Because we only need to know if a ruleset is valid,
+    // we need a data structure that looks ahead to see if any of its requisites are present,
+    // marking it invalid.
+
+    let mut encoded_rules = HashMap::new();
+    for (required, follows) in rules {
+        if !encoded_rules.contains_key(follows) {
+            encoded_rules.insert(*follows, Vec::new());
+        }
+        encoded_rules
+            .entry(*follows)
+            .and_modify(|e| e.push(*required));
+    }
+    encoded_rules
+}
+
+pub fn is_update_valid(update: &[i32], ruleset: &HashMap<i32, Vec<i32>>) -> bool {
+    for (i, v) in update.iter().enumerate() {
+        if ruleset.contains_key(v) {
+            let rule = ruleset.get(v).unwrap();
+            for later in &update[i + 1..] {
+                if rule.contains(later) {
+                    return false;
+                }
+            }
+        }
+    }
+    true
+}
+
+/// A page that must come before another page, as `(before, after)`
+pub type Rule = (i32, i32);
+
+pub fn parse_input(file: &str) -> Result<(Vec<Rule>, Vec<Vec<i32>>), ParseError> {
+    let [rule_lines, update_lines] = sections_of(file, "ordering rules, then updates")?;
+
+    let mut rules = Vec::new();
+    for line in rule_lines {
+        let (requires, follows) = line.key_value("|")?;
+        rules.push((requires.integer()?, follows.integer()?));
+    }
+
+    let updates = update_lines
+        .into_iter()
+        .map(|line| line.integer_list(","))
+        .collect::<Result<_, _>>()?;
+    Ok((rules, updates))
+}
+
+pub fn part_one(file: &str) -> i32 {
+    let (rules, updates) = expect_valid(parse_input(file));
+    let ruleset = build_ordering_ruleset(&rules);
+
+    let mut median_of_valid_updates = 0;
+    for update in updates {
+        if is_update_valid(&update, &ruleset) {
+            let median_index = update.len() / 2;
+            median_of_valid_updates += update[median_index];
+        }
+    }
+
+    median_of_valid_updates
+}
+
+/// Puts each invalid update in order one swap at a time, spending a step of `budget` on each
+pub fn repair_updates(file: &str, budget: &mut Budget) -> Result<i32, BudgetExceeded> {
+    let (rules, updates) = expect_valid(parse_input(file));
+    let ruleset = build_ordering_ruleset(&rules);
+
+    let mut median_of_invalid_updates = 0;
+    for mut update in updates {
+        if !is_update_valid(&update, &ruleset) {
+            'fix: loop {
+                for (i, x) in update.iter().enumerate() {
+                    // Find the next element that has components out of place
+                    if ruleset.contains_key(x) {
+                        let rule = ruleset.get(x).unwrap();
+                        for j in i + 1..update.len() {
+                            if rule.contains(&update[j]) {
+                                budget.spend()?;
+                                let el = update.remove(j);
+                                update.insert(j - 1, el);
+                                continue 'fix;
+                            }
+                        }
+                    }
+                }
+                break 'fix;
+            }
+            let median_index = update.len() / 2;
+            median_of_invalid_updates += update[median_index];
+        }
+    }
+
+    Ok(median_of_invalid_updates)
+}
+
+pub fn part_two(file: &str) -> Result<i32, BudgetExceeded> {
+    repair_updates(file, &mut Budget::new(Params::default().budget))
+}
+
+/// How many swaps part two may make while repairing updates
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Params {
+    pub budget: u64,
+}
+
+impl Default for Params {
+    fn default() -> Params {
+        Params { budget: 1_000_000 }
+    }
+}
+
+impl Parameters for Params {
+    fn configure(settings: &Settings) -> Result<Params, ParamError> {
+        settings.only(&["budget"])?;
+        Ok(Params {
+            budget: settings.get_or("budget", Params::default().budget)?,
+        })
+    }
+}
+
+pub struct Solver;
+
+impl Solution for Solver {
+    type Params = Params;
+
+    fn parse(input: &str) -> Result<(), ParseError> {
+        black_box(parse_input(input))?;
+        Ok(())
+    }
+
+    fn part_one(input: &str, _: &Params) -> Solved {
+        Ok(part_one(input).into())
+    }
+
+    fn part_two(input: &str, params: &Params) -> Solved {
+        Ok(repair_updates(input, &mut Budget::new(params.budget))?.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one_given() {
+        let file = "47|53
+97|13
+97|61
+97|47
+75|29
+61|13
+75|53
+29|13
+97|29
+53|29
+61|53
+97|53
+61|29
+47|13
+75|47
+97|75
+47|61
+75|61
+47|29
+75|13
+53|13
+
+75,47,61,53,29
+97,61,53,29,13
+75,29,13
+75,97,47,61,53
+61,13,29
+97,13,75,29,47";
+        let result = part_one(file);
+        assert_eq!(result, 143);
+    }
+
+    #[test]
+    fn test_part_two_given() {
+        let file = "47|53
+97|13
+97|61
+97|47
+75|29
+61|13
+75|53
+29|13
+97|29
+53|29
+61|53
+97|53
+61|29
+47|13
+75|47
+97|75
+47|61
+75|61
+47|29
+75|13
+53|13
+
+75,47,61,53,29
+97,61,53,29,13
+75,29,13
+75,97,47,61,53
+61,13,29
+97,13,75,29,47";
+        let result = part_two(file);
+        assert_eq!(result, Ok(123));
+        // The example's three invalid updates take six swaps between them
+        assert_eq!(repair_updates(file, &mut Budget::new(6)), Ok(123));
+        assert_eq!(
+            repair_updates(file, &mut Budget::new(5)),
+            Err(BudgetExceeded { steps: 5 })
+        );
+    }
+}
@@ -0,0 +1,295 @@
+//! Day 6: Guard Gallivant
+
+use crate::{
+    animation::Recorder,
+    answer::{SolveError, Solved},
+    cycles,
+    geometry::{Direction, Point},
+    grid::Grid,
+    parse::{expect_valid, marked_char_grid, non_blank_lines, ParseError},
+    render::{Canvas, Colour},
+    warn, Solution,
+};
+use std::{collections::HashSet, hint::black_box};
+
+pub fn parse_data(data: &str) -> Result<Grid<char>, ParseError> {
+    marked_char_grid(non_blank_lines(data), &['^'])
+}
+
+fn find_guard(matrix: &Grid<char>) -> Point {
+    Point::from(matrix.find(&'^').expect("lab map should have a guard"))
+}
+
+/// Where the guard is and which way they are facing
+type Guard = (Point, Direction);
+
+/// Moves the guard one step forward, or turns them right if a wall or the extra `obstacle` is in
+/// the way. `None` once the guard walks out of the lab
+fn step_guard(
+    matrix: &Grid<char>,
+    obstacle: Option<Point>,
+    &(guard, direction): &Guard,
+) -> Option<Guard> {
+    let ahead = guard + direction.vector();
+    let position = matrix.position(ahead.x, ahead.y)?;
+    if matrix[position] == '#' || Some(ahead) == obstacle {
+        Some((guard, direction.clockwise()))
+    } else {
+        Some((ahead, direction))
+    }
+}
+
+/// Every state of the guard's walk out of the lab, failing if they never leave
+fn patrol(matrix: &Grid<char>) -> Result<Vec<Guard>, SolveError> {
+    let start = (find_guard(matrix), Direction::Up);
+    let history = cycles::record(start, |guard| step_guard(matrix, None, guard));
+    if history.cycle.is_some() {
+        return Err(SolveError::NoAnswer(String::from(
+            "the guard walks in a loop and never leaves the lab",
+        )));
+    }
+    Ok(history.states)
+}
+
+pub fn part_one(data: &str) -> Result<u32, SolveError> {
+    let matrix = expect_valid(parse_data(data));
+    let visited = patrol(&matrix)?
+        .into_iter()
+        .map(|(guard, _)| guard)
+        .collect::<HashSet<_>>();
+    Ok(visited.len() as u32)
+}
+
+/// Every spot where one more obstacle would trap the guard in a loop, given their walk out of the
+/// lab without it
+fn loop_obstacles(matrix: &Grid<char>, path: &[Guard]) -> Vec<Point> {
+    // General algorithm:
+    // Try to put an obstacle directly in front of the guard, the first time they are about to
+    // walk onto each tile (later on, the obstacle would have blocked their earlier path)
+    // If the guard then never leaves, the obstacle makes them loop
+    let mut tried = HashSet::from([find_guard(matrix)]);
+    let mut obstacles = Vec::new();
+    for &guard in path {
+        let ahead = guard.0 + guard.1.vector();
+        let Some(position) = matrix.position(ahead.x, ahead.y) else {
+            continue;
+        };
+        if matrix[position] == '#' || !tried.insert(ahead) {
+            continue;
+        }
+        if cycles::brent(guard, |guard| step_guard(matrix, Some(ahead), guard)).is_some() {
+            obstacles.push(ahead);
+        }
+    }
+    obstacles
+}
+
+pub fn part_two(data: &str) -> Result<u32, SolveError> {
+    let matrix = expect_valid(parse_data(data));
+    Ok(loop_obstacles(&matrix, &patrol(&matrix)?).len() as u32)
+}
+
+/// The guard's walk out of the lab, with the spots for a looping obstacle marked `O`
+pub fn render(data: &str) -> Result<Canvas, SolveError> {
+    let matrix = expect_valid(parse_data(data));
+    let path = patrol(&matrix)?;
+    let obstacles = loop_obstacles(&matrix, &path);
+    let mut canvas = Canvas::from_chars(&matrix);
+    canvas
+        .path(
+            path.iter()
+                .filter_map(|&(guard, direction)| Some((guard.position()?, direction))),
+            Colour::GREEN,
+        )
+        .mark(
+            obstacles.iter().filter_map(|obstacle| obstacle.position()),
+            'O',
+            Some(Colour::RED),
+        );
+    Ok(canvas)
+}
+
+/// Records the guard's walk one step at a time, leaving a trail of arrows behind them
+pub fn animate(data: &str, recorder: &mut Recorder) -> Result<(), SolveError> {
+    let matrix = expect_valid(parse_data(data));
+    let mut trail = Canvas::from_chars(&matrix);
+    let frame = |trail: &Canvas, &(guard, direction): &Guard| {
+        let mut frame = trail.clone();
+        frame.mark(guard.position(), direction.arrow(), Some(Colour::RED));
+        frame
+    };
+    let path = patrol(&matrix)?;
+    for guard in &path {
+        recorder.record(|| frame(&trail, guard));
+        trail.path(
+            guard.0.position().map(|position| (position, guard.1)),
+            Colour::GREEN,
+        );
+    }
+    let last = path.last().expect("the guard should start in the lab");
+    recorder.finish(|| frame(&trail, last));
+    Ok(())
+}
+
+pub struct Solver;
+
+impl Solution for Solver {
+    type Params = ();
+
+    fn parse(input: &str) -> Result<(), ParseError> {
+        black_box(parse_data(input))?;
+        Ok(())
+    }
+
+    fn part_one(input: &str, _: &()) -> Solved {
+        Ok(part_one(input)?.into())
+    }
+
+    fn part_two(input: &str, _: &()) -> Solved {
+        Ok(part_two(input)?.into())
+    }
+
+    fn render(input: &str, _: &()) -> Option<Canvas> {
+        render(input).inspect_err(|error| warn!("{error}")).ok()
+    }
+
+    fn animate(input: &str, _: &(), recorder: &mut Recorder) -> bool {
+        animate(input, recorder)
+            .inspect_err(|error| warn!("{error}"))
+            .is_ok()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one_as_given() {
+        let data = "....#.....
+.........#
+..........
+..#.......
+.......#..
+..........
+.#..^.....
+........#.
+#.........
+......#...";
+        let result = part_one(data);
+        assert_eq!(result, Ok(41));
+    }
+
+    #[test]
+    fn test_part_two_as_given() {
+        let data = "....#.....
+.........#
+..........
+..#.......
+.......#..
+..........
+.#..^.....
+........#.
+#.........
+......#...";
+        let result = part_two(data);
+        assert_eq!(result, Ok(6));
+    }
+
+    #[test]
+    fn test_part_two_not_covered() {
+        // In which I attempt to reconcile the fact that tests run but I have the wrong answer by
+        // trying to find a set of input data that is wrong
+        let data = "
+....#.....
+......#...
+....^.....
+.....#....";
+        let result = part_two(data);
+        assert_eq!(result, Ok(1));
+    }
+
+    #[test]
+    fn test_part_two_not_covered2() {
+        let data = "
+..###.....
+......#...
+#...^.....
+.....#....";
+        let result = part_two(data);
+        assert_eq!(result, Ok(4));
+    }
+
+    #[test]
+    fn test_part_two_phantom_loop() {
+        let data = "
+.....
+...#.
+#^...
+..#..";
+        let result = part_two(data);
+        assert_eq!(result, Ok(1));
+    }
+
+    #[test]
+    fn test_part_two_phantom_dangling_loop() {
+        let data = "
+...#..
+.....#
+.^#...
+....#.";
+        let result = part_two(data);
+        assert_eq!(result, Ok(1));
+    }
+
+    #[test]
+    fn test_part_two_not_blocks_approach() {
+        let data = "
+.#.
+#.#
+...
+...
+.^.
+";
+        let result = part_two(data);
+        assert_eq!(result, Ok(0));
+    }
+
+    #[test]
+    fn test_render_marks_path_and_obstacles() {
+        let data = "....#.....
+.........#
+..........
+..#.......
+.......#..
+..........
+.#..^.....
+........#.
+#.........
+......#...";
+        let text = render(data).unwrap().to_text();
+        assert_eq!(text.matches('O').count(), 6);
+        // Every obstacle spot is somewhere the guard would have walked
+        let walked = text.chars().filter(|c| "^>v<O".contains(*c)).count();
+        assert_eq!(walked, 41);
+    }
+
+    #[test]
+    fn test_missing_guard_is_reported() {
+        let error = parse_data("..#\n...\n").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 2, column 4: expected a '^' in the grid"
+        );
+    }
+
+    #[test]
+    fn test_boxed_in_guard_is_reported() {
+        let data = ".#.\n#^#\n.#.\n";
+        let error = SolveError::NoAnswer(String::from(
+            "the guard walks in a loop and never leaves the lab",
+        ));
+        assert_eq!(part_one(data), Err(error.clone()));
+        assert_eq!(part_two(data), Err(error));
+    }
+}
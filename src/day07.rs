@@ -0,0 +1,128 @@
+//! Day 7: Bridge Repair
+
+use crate::{
+    answer::Solved,
+    parse::{expect_valid, non_blank_lines, ErrorKind, ParseError},
+    Solution,
+};
+use std::hint::black_box;
+
+/// Each equation's test value with the numbers that should combine to make it
+pub fn parse_input(file: &str) -> Result<Vec<(u64, Vec<u64>)>, ParseError> {
+    non_blank_lines(file)
+        .map(|line| {
+            let (result, operands) = line.key_value(":")?;
+            let operands = operands.integers()?;
+            if operands.is_empty() {
+                return Err(line.error_at(
+                    line.text.len(),
+                    ErrorKind::Expected(String::from("at least one number")),
+                ));
+            }
+            Ok((result.integer()?, operands))
+        })
+        .collect()
+}
+
+pub fn recursive_explore(target: u64, current: u64, remaining: &[u64]) -> bool {
+    if current > target {
+        return false;
+    }
+    if !remaining.is_empty() {
+        return recursive_explore(target, current + remaining[0], &remaining[1..])
+            || recursive_explore(target, current * remaining[0], &remaining[1..]);
+    }
+    current == target
+}
+
+pub fn part_two_explore(target: u64, current: u64, remaining: &[u64]) -> bool {
+    if current > target {
+        return false;
+    }
+    if !remaining.is_empty() {
+        return part_two_explore(target, current + remaining[0], &remaining[1..])
+            || part_two_explore(target, current * remaining[0], &remaining[1..])
+            || part_two_explore(
+                target,
+                format!("{}{}", current, remaining[0])
+                    .parse::<u64>()
+                    .unwrap(),
+                &remaining[1..],
+            );
+    }
+    current == target
+}
+
+pub fn either_part(file: &str, func: &dyn Fn(u64, u64, &[u64]) -> bool) -> u64 {
+    let mut sum = 0;
+    for (result, operands) in expect_valid(parse_input(file)) {
+        if func(result, operands[0], &operands[1..]) {
+            sum += result;
+        }
+    }
+    sum
+}
+
+pub fn part_one(file: &str) -> u64 {
+    either_part(file, &recursive_explore)
+}
+
+pub fn part_two(file: &str) -> u64 {
+    either_part(file, &part_two_explore)
+}
+
+pub struct Solver;
+
+impl Solution for Solver {
+    type Params = ();
+
+    fn parse(input: &str) -> Result<(), ParseError> {
+        black_box(parse_input(input))?;
+        Ok(())
+    }
+
+    fn part_one(input: &str, _: &()) -> Solved {
+        Ok(part_one(input).into())
+    }
+
+    fn part_two(input: &str, _: &()) -> Solved {
+        Ok(part_two(input).into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one_as_given() {
+        let data = "190: 10 19
+3267: 81 40 27
+83: 17 5
+156: 15 6
+7290: 6 8 6 15
+161011: 16 10 13
+192: 17 8 14
+21037: 9 7 18 13
+292: 11 6 16 20";
+
+        let result = part_one(&String::from(data));
+        assert_eq!(result, 3749);
+    }
+
+    #[test]
+    fn test_part_two_as_given() {
+        let data = "190: 10 19
+3267: 81 40 27
+83: 17 5
+156: 15 6
+7290: 6 8 6 15
+161011: 16 10 13
+192: 17 8 14
+21037: 9 7 18 13
+292: 11 6 16 20";
+
+        let result = part_two(&String::from(data));
+        assert_eq!(result, 11387);
+    }
+}
@@ -0,0 +1,149 @@
+//! Day 8: Resonant Collinearity
+
+use crate::{
+    answer::Solved,
+    grid::Grid,
+    parse::{char_grid, expect_valid, non_blank_lines, ParseError},
+    Solution,
+};
+use std::{collections::HashMap, hint::black_box};
+
+pub fn parse_input(file: &str) -> Result<Grid<char>, ParseError> {
+    char_grid(non_blank_lines(file))
+}
+
+pub fn find_antennas(map: &Grid<char>) -> HashMap<char, Vec<(i32, i32)>> {
+    let mut result = HashMap::new();
+    for ((x, y), &c) in map.iter() {
+        if c == '.' {
+            continue;
+        }
+        let v = result.entry(c).or_insert(Vec::new());
+        v.push((x as i32, y as i32));
+    }
+    result
+}
+
+pub fn find_antinode_single(a1: (i32, i32), a2: (i32, i32), bounds: (i32, i32)) -> Vec<(i32, i32)> {
+    // Core idea: When nodes are seperated by dx in x and dy in y, the antinodes are located at
+    // (x1 - dx, y1 - dy) and (x2 + dx, y2 + dy)
+    let mut result = Vec::new();
+    let dx = a2.0 - a1.0;
+    let dy = a2.1 - a1.1;
+    if a1.0 - dx >= 0 && a1.1 - dy >= 0 && a1.0 - dx < bounds.0 && a1.1 - dy < bounds.1 {
+        result.push((a1.0 - dx, a1.1 - dy));
+    }
+    if a2.0 + dx >= 0 && a2.1 + dy >= 0 && a2.0 + dx < bounds.0 && a2.1 + dy < bounds.1 {
+        result.push((a2.0 + dx, a2.1 + dy));
+    }
+    result
+}
+
+pub fn find_antinodes_multiple(
+    a1: (i32, i32),
+    a2: (i32, i32),
+    bounds: (i32, i32),
+) -> Vec<(i32, i32)> {
+    // Core idea: When nodes are seperated by dx in x and dy in y, the antinodes are located at
+    // (x1 - dx, y1 - dy) and (x2 + dx, y2 + dy)
+    let mut result = Vec::new();
+    let dx = a2.0 - a1.0;
+    let dy = a2.1 - a1.1;
+    let mut i = 0;
+    while a1.0 - i * dx >= 0
+        && a1.1 - i * dy >= 0
+        && a1.0 - i * dx < bounds.0
+        && a1.1 - i * dy < bounds.1
+    {
+        result.push((a1.0 - i * dx, a1.1 - i * dy));
+        i += 1;
+    }
+    i = 0;
+    while a2.0 + i * dx >= 0
+        && a2.1 + i * dy >= 0
+        && a2.0 + i * dx < bounds.0
+        && a2.1 + i * dy < bounds.1
+    {
+        result.push((a2.0 + i * dx, a2.1 + i * dy));
+        i += 1;
+    }
+    result
+}
+
+pub type AntinodeFinder = fn((i32, i32), (i32, i32), (i32, i32)) -> Vec<(i32, i32)>;
+
+pub fn either_part(file: &str, find_antinodes: AntinodeFinder) -> i64 {
+    let parsed_input = expect_valid(parse_input(file));
+    let bounds = (parsed_input.width() as i32, parsed_input.height() as i32);
+    let antenna_map = find_antennas(&parsed_input);
+    let mut map = parsed_input.map(|_| false);
+
+    for (_c, antenna_locations) in antenna_map {
+        for (i, loc1) in antenna_locations.iter().enumerate() {
+            for loc2 in &antenna_locations[i + 1..] {
+                for (x, y) in find_antinodes(*loc1, *loc2, bounds) {
+                    map[(x as usize, y as usize)] = true;
+                }
+            }
+        }
+    }
+
+    map.iter().filter(|(_, &antinode)| antinode).count() as i64
+}
+
+pub fn part_one(file: &str) -> i64 {
+    either_part(file, find_antinode_single)
+}
+
+pub fn part_two(file: &str) -> i64 {
+    either_part(file, find_antinodes_multiple)
+}
+
+pub struct Solver;
+
+impl Solution for Solver {
+    type Params = ();
+
+    fn parse(input: &str) -> Result<(), ParseError> {
+        black_box(parse_input(input))?;
+        Ok(())
+    }
+
+    fn part_one(input: &str, _: &()) -> Solved {
+        Ok(part_one(input).into())
+    }
+
+    fn part_two(input: &str, _: &()) -> Solved {
+        Ok(part_two(input).into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE_DATA: &str = "............
+........0...
+.....0......
+.......0....
+....0.......
+......A.....
+............
+............
+........A...
+.........A..
+............
+............";
+
+    #[test]
+    fn test_part_one_as_given() {
+        let result = part_one(&String::from(EXAMPLE_DATA));
+        assert_eq!(result, 14);
+    }
+
+    #[test]
+    fn test_part_two_as_given() {
+        let result = part_two(&String::from(EXAMPLE_DATA));
+        assert_eq!(result, 34);
+    }
+}
@@ -0,0 +1,147 @@
+//! Day 9: Disk Fragmenter
+
+use crate::{
+    answer::Solved,
+    parse::{expect_valid, non_blank_lines, ParseError},
+    Solution,
+};
+use std::hint::black_box;
+
+pub fn parse_input(file: &str) -> Result<Vec<i64>, ParseError> {
+    let mut digits = Vec::new();
+    for line in non_blank_lines(file) {
+        digits.extend(
+            line.trim()
+                .cells("a digit", |c| c.to_digit(10).map(i64::from))?,
+        );
+    }
+    Ok(digits)
+}
+
+pub fn part_one(file: &str) -> i64 {
+    let parsed_input = expect_valid(parse_input(file));
+    let mut input_left = 0;
+    let mut input_right = parsed_input.len() - 1;
+    let mut right_leftovers = parsed_input[input_right];
+    let mut checksum = 0;
+    let mut output_index = 0;
+    let mut this_block_is_file = true;
+    while input_left < input_right {
+        if this_block_is_file {
+            // File space; include as normal
+            let n = parsed_input[input_left];
+            checksum += (input_left as i64 / 2) * (n * output_index + n * (n - 1) / 2); // n-1 due to starting at zero
+            output_index += n;
+        } else {
+            // Empty space; fill from right
+            let mut empty_spaces = parsed_input[input_left];
+            while empty_spaces > 0 && input_left < input_right {
+                if right_leftovers == 0 {
+                    input_right -= 2;
+                    right_leftovers = parsed_input[input_right];
+                    continue;
+                }
+                right_leftovers -= 1;
+                checksum += output_index * input_right as i64 / 2;
+                empty_spaces -= 1;
+                output_index += 1;
+            }
+            if right_leftovers == 0 {
+                input_right -= 2;
+                right_leftovers = parsed_input[input_right];
+            }
+        }
+        input_left += 1;
+        this_block_is_file = !this_block_is_file;
+    }
+    for _i in 0..right_leftovers {
+        checksum += output_index * input_right as i64 / 2;
+        output_index += 1;
+    }
+    checksum
+}
+
+pub fn part_two(file: &str) -> i64 {
+    let parsed_input = expect_valid(parse_input(file));
+    let mut holes = Vec::new();
+    let mut files = Vec::new();
+    let mut moved_files = Vec::new();
+    let mut index: usize = 0;
+    for (i, &x) in parsed_input.iter().enumerate() {
+        if i % 2 == 0 {
+            files.push((index, x));
+        } else {
+            holes.push((index, x));
+        }
+        index += x as usize;
+    }
+    files.reverse();
+    'files: for &(i, x) in files.iter() {
+        // Try to move to earliest hole
+        for (hole_index, &(j, y)) in holes.iter().enumerate() {
+            if y >= x && j < i {
+                moved_files.push((j, x));
+                holes[hole_index] = (j + x as usize, y - x);
+                continue 'files;
+            }
+        }
+        // Nowhere to move to
+        moved_files.push((i, x));
+    }
+    let mut checksum = 0;
+    let len = moved_files.len();
+    for (index, &(i, x)) in moved_files.iter().enumerate() {
+        checksum += (len - 1 - index) as i64 * (x * i as i64 + x * (x - 1) / 2);
+    }
+    checksum
+}
+
+pub struct Solver;
+
+impl Solution for Solver {
+    type Params = ();
+
+    fn parse(input: &str) -> Result<(), ParseError> {
+        black_box(parse_input(input))?;
+        Ok(())
+    }
+
+    fn part_one(input: &str, _: &()) -> Solved {
+        Ok(part_one(input).into())
+    }
+
+    fn part_two(input: &str, _: &()) -> Solved {
+        Ok(part_two(input).into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE_DATA: &str = "2333133121414131402";
+
+    #[test]
+    fn test_part_one_simple() {
+        let result = part_one(&String::from("11111"));
+        assert_eq!(result, 4);
+    }
+
+    #[test]
+    fn test_part_one_as_given() {
+        let result = part_one(&String::from(EXAMPLE_DATA));
+        assert_eq!(result, 1928);
+    }
+
+    #[test]
+    fn test_part_two_simple() {
+        let result = part_two(&String::from("11111"));
+        assert_eq!(result, 4);
+    }
+
+    #[test]
+    fn test_part_two_as_given() {
+        let result = part_two(&String::from(EXAMPLE_DATA));
+        assert_eq!(result, 2858);
+    }
+}
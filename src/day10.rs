@@ -0,0 +1,113 @@
+//! Day 10: Hoof It
+
+use crate::{
+    answer::Solved,
+    grid::{Grid, Position},
+    parse::{expect_valid, grid, non_blank_lines, ParseError},
+    Solution,
+};
+use std::{collections::HashSet, hint::black_box};
+
+pub fn parse_input(file: &str) -> Result<Grid<u32>, ParseError> {
+    grid(non_blank_lines(file), "a digit", |c| c.to_digit(10))
+}
+
+/// Positions next to `position` that are exactly one step higher
+fn uphill(map: &Grid<u32>, position: Position) -> impl Iterator<Item = Position> + '_ {
+    let next = map[position] + 1;
+    map.neighbours(position)
+        .filter(move |&neighbour| map[neighbour] == next)
+}
+
+pub fn find_trailheads_nonunique(map: &Grid<u32>, position: Position) -> usize {
+    if map[position] == 9 {
+        return 1;
+    }
+    uphill(map, position)
+        .map(|neighbour| find_trailheads_nonunique(map, neighbour))
+        .sum()
+}
+
+pub fn find_trailheads(map: &Grid<u32>, position: Position) -> HashSet<Position> {
+    if map[position] == 9 {
+        return HashSet::from([position]);
+    }
+    let mut from_adjacents = HashSet::new();
+    for neighbour in uphill(map, position) {
+        from_adjacents.extend(find_trailheads(map, neighbour));
+    }
+    from_adjacents
+}
+
+pub fn part_one(file: &str) -> i64 {
+    let digit_map = expect_valid(parse_input(file));
+
+    // Start with naive "dumb" implementation
+    let sum_score: usize = digit_map
+        .find_all(&0)
+        .map(|start| find_trailheads(&digit_map, start).len())
+        .sum();
+    sum_score as i64
+}
+
+pub fn part_two(file: &str) -> i64 {
+    let digit_map = expect_valid(parse_input(file));
+
+    // Start with naive "dumb" implementation
+    let sum_score: usize = digit_map
+        .find_all(&0)
+        .map(|start| find_trailheads_nonunique(&digit_map, start))
+        .sum();
+    sum_score as i64
+}
+
+pub struct Solver;
+
+impl Solution for Solver {
+    type Params = ();
+
+    fn parse(input: &str) -> Result<(), ParseError> {
+        black_box(parse_input(input))?;
+        Ok(())
+    }
+
+    fn part_one(input: &str, _: &()) -> Solved {
+        Ok(part_one(input).into())
+    }
+
+    fn part_two(input: &str, _: &()) -> Solved {
+        Ok(part_two(input).into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE_DATA: &str = "89010123
+78121874
+87430965
+96549874
+45678903
+32019012
+01329801
+10456732";
+
+    #[test]
+    fn test_part_one_simple() {
+        let result = part_one(&String::from("99999999999\n90123456789\n99999999999"));
+        assert_eq!(result, 3);
+    }
+
+    #[test]
+    fn test_part_one_as_given() {
+        let result = part_one(&String::from(EXAMPLE_DATA));
+        assert_eq!(result, 36);
+    }
+
+    #[test]
+    fn test_part_two_as_given() {
+        let result = part_two(&String::from(EXAMPLE_DATA));
+        assert_eq!(result, 81);
+    }
+}
@@ -0,0 +1,175 @@
+//! Day 11: Plutonian Pebbles
+
+use crate::{
+    answer::{SolveError, Solved},
+    debug,
+    memo::Memo,
+    params::{ParamError, Parameters, Settings},
+    parse::{expect_valid, non_blank_lines, ParseError},
+    Solution,
+};
+use std::hint::black_box;
+
+pub fn parse_input(file: &str) -> Result<Vec<u64>, ParseError> {
+    let mut stones = Vec::new();
+    for line in non_blank_lines(file) {
+        stones.extend(line.integers::<u64>()?);
+    }
+    Ok(stones)
+}
+
+/// The stones a stone turns into after one blink, or `None` if its number grows past a `u64`
+pub fn blink_once(input: u64) -> Option<(u64, Option<u64>)> {
+    if input == 0 {
+        return Some((1, None));
+    }
+    let s = input.to_string();
+    if s.len().is_multiple_of(2) {
+        let midpoint = s.len() / 2;
+        let left = &s[..midpoint].parse::<u64>().unwrap();
+        let right = &s[midpoint..].parse::<u64>().unwrap();
+        return Some((*left, Some(*right)));
+    }
+    Some((input.checked_mul(2024)?, None))
+}
+
+/// How many stones a single stone becomes after `blinks` blinks, or `None` if a number overflows.
+/// Stones don't affect each other, and the same small numbers keep turning up, so each
+/// (stone, blinks) pair is only counted once
+pub fn count_stones(
+    memo: &mut Memo<(u64, u64), Option<u64>>,
+    stone: u64,
+    blinks: u64,
+) -> Option<u64> {
+    if blinks == 0 {
+        return Some(1);
+    }
+    memo.get_or_compute((stone, blinks), |memo| match blink_once(stone)? {
+        (left, None) => count_stones(memo, left, blinks - 1),
+        (left, Some(right)) => count_stones(memo, left, blinks - 1)?.checked_add(count_stones(
+            memo,
+            right,
+            blinks - 1,
+        )?),
+    })
+}
+
+pub fn blink_at_stones(file: &str, blinks: u64) -> Result<u64, SolveError> {
+    let parsed_input = expect_valid(parse_input(file));
+    let mut memo = Memo::new();
+    let count = parsed_input.into_iter().try_fold(0u64, |total, stone| {
+        total.checked_add(count_stones(&mut memo, stone, blinks)?)
+    });
+    debug!("stone counts memo: {}", memo.stats());
+    count.ok_or_else(|| {
+        SolveError::NoAnswer(format!(
+            "the stones number more than a u64 holds after {blinks} blinks"
+        ))
+    })
+}
+
+/// How many times the stones are blinked at in each part
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Params {
+    pub part_one_blinks: u64,
+    pub part_two_blinks: u64,
+}
+
+impl Default for Params {
+    fn default() -> Params {
+        Params {
+            part_one_blinks: 25,
+            part_two_blinks: 75,
+        }
+    }
+}
+
+/// `--blinks` sets the number of blinks for both parts
+impl Parameters for Params {
+    fn configure(settings: &Settings) -> Result<Params, ParamError> {
+        settings.only(&["blinks"])?;
+        let defaults = Params::default();
+        Ok(Params {
+            part_one_blinks: settings.get_or("blinks", defaults.part_one_blinks)?,
+            part_two_blinks: settings.get_or("blinks", defaults.part_two_blinks)?,
+        })
+    }
+}
+
+pub fn part_one(input: &str) -> Result<u64, SolveError> {
+    blink_at_stones(input, Params::default().part_one_blinks)
+}
+
+pub fn part_two(input: &str) -> Result<u64, SolveError> {
+    blink_at_stones(input, Params::default().part_two_blinks)
+}
+
+pub struct Solver;
+
+impl Solution for Solver {
+    type Params = Params;
+
+    fn parse(input: &str) -> Result<(), ParseError> {
+        black_box(parse_input(input))?;
+        Ok(())
+    }
+
+    fn part_one(input: &str, params: &Params) -> Solved {
+        Ok(blink_at_stones(input, params.part_one_blinks)?.into())
+    }
+
+    fn part_two(input: &str, params: &Params) -> Solved {
+        Ok(blink_at_stones(input, params.part_two_blinks)?.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE_DATA: &str = "125 17";
+
+    #[test]
+    fn test_zero_compute() {
+        let result = blink_at_stones(&String::from("0"), 25);
+        assert_eq!(result, Ok(19778));
+    }
+    #[test]
+    fn test_discrepancy() {
+        let result = blink_at_stones(&String::from("32772608"), 4);
+        assert_eq!(result, Ok(7));
+    }
+    #[test]
+    fn test_part_one_one_blink() {
+        let result = blink_at_stones(&String::from(EXAMPLE_DATA), 1);
+        assert_eq!(result, Ok(3));
+    }
+    #[test]
+    fn test_part_one_no_blink() {
+        let result = blink_at_stones(&String::from(EXAMPLE_DATA), 0);
+        assert_eq!(result, Ok(2));
+    }
+
+    #[test]
+    fn test_part_one_six_blinks() {
+        let result = blink_at_stones(&String::from(EXAMPLE_DATA), 6);
+        assert_eq!(result, Ok(22));
+    }
+
+    #[test]
+    fn test_part_one_as_given() {
+        let result = blink_at_stones(&String::from(EXAMPLE_DATA), 25);
+        assert_eq!(result, Ok(55312));
+    }
+
+    #[test]
+    fn test_too_many_blinks() {
+        assert_eq!(blink_once(u64::MAX / 1000), None);
+        assert_eq!(
+            blink_at_stones(EXAMPLE_DATA, 200),
+            Err(SolveError::NoAnswer(String::from(
+                "the stones number more than a u64 holds after 200 blinks"
+            )))
+        );
+    }
+}
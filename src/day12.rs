@@ -0,0 +1,244 @@
+//! Day 12: Garden Groups
+
+use crate::{
+    answer::Solved,
+    grid::{Grid, Position},
+    parse::{char_grid, expect_valid, non_blank_lines, ParseError},
+    regions::{Bounds, Connectivity, Region, Regions},
+    render::Canvas,
+    Solution,
+};
+use std::hint::black_box;
+
+pub fn parse_input(file: &str) -> Result<Grid<char>, ParseError> {
+    char_grid(non_blank_lines(file))
+}
+
+pub fn find_regions(grid: &Grid<char>) -> Regions {
+    Regions::of_equal(grid, Connectivity::Orthogonal)
+}
+
+pub fn part_one(file: &str) -> i64 {
+    let grid = expect_valid(parse_input(file));
+    let regions = find_regions(&grid);
+    let price: usize = regions
+        .iter()
+        .map(|region| region.area() * regions.perimeter(region.id))
+        .sum();
+    price as i64
+}
+
+pub fn count_region_sides(regions: &Regions, region: &Region) -> i32 {
+    // Basic idea: Go left-to-right, and then top-to-bottom
+    // Each time we enter and subsequently leave the shape, we add 2 sides
+    // Each time we note two points as being entrances/exits, we mark all points in transverse
+    // directions as visited
+    let Bounds {
+        left,
+        top,
+        right,
+        bottom,
+    } = region.bounds;
+    let contains = |&position: &Position| regions.id_at(position) == Some(region.id);
+
+    let mut inside_shape = false;
+    let mut entrance = 0;
+    let mut sides = 0;
+    // Left-to-right
+    for y in top..=bottom {
+        for x in left..=right {
+            if contains(&(x, y)) {
+                if !inside_shape {
+                    entrance = x;
+                    inside_shape = true;
+                }
+            } else {
+                if inside_shape {
+                    sides += 2;
+                    if y > 0
+                        && contains(&(entrance, y - 1))
+                        && (entrance == 0 || !contains(&(entrance - 1, y - 1)))
+                    {
+                        sides -= 1; // Left edge entrance already counted
+                    }
+                    if y > 0 && contains(&(x - 1, y - 1)) && !contains(&(x, y - 1)) {
+                        sides -= 1; // Right edge exit already counted
+                    }
+                    inside_shape = false;
+                }
+            }
+        }
+        if inside_shape {
+            sides += 2;
+            if y > 0
+                && contains(&(entrance, y - 1))
+                && (entrance == 0 || !contains(&(entrance - 1, y - 1)))
+            {
+                sides -= 1; // Left edge entrance already counted
+            }
+            if y > 0 && contains(&(right, y - 1)) {
+                sides -= 1; // Right edge exit already counted
+            }
+            inside_shape = false;
+        }
+    }
+    // Top-to-bottom
+    inside_shape = false;
+    for x in left..=right {
+        for y in top..=bottom {
+            if contains(&(x, y)) {
+                if !inside_shape {
+                    entrance = y;
+                    inside_shape = true;
+                }
+            } else {
+                if inside_shape {
+                    sides += 2;
+                    if x > 0
+                        && contains(&(x - 1, entrance))
+                        && (entrance == 0 || !contains(&(x - 1, entrance - 1)))
+                    {
+                        sides -= 1; // Top edge entrance already counted
+                    }
+                    if x > 0 && contains(&(x - 1, y - 1)) && !contains(&(x - 1, y)) {
+                        sides -= 1; // Bottom edge exit already coutned
+                    }
+                    inside_shape = false;
+                }
+            }
+        }
+        if inside_shape {
+            sides += 2;
+            if x > 0
+                && contains(&(x - 1, entrance))
+                && (entrance == 0 || !contains(&(x - 1, entrance - 1)))
+            {
+                sides -= 1; // Top edge entrance already counted
+            }
+            if x > 0 && contains(&(x - 1, bottom)) {
+                sides -= 1; // Bottom edge exit already coutned
+            }
+            inside_shape = false;
+        }
+    }
+    sides
+}
+
+pub fn part_two(file: &str) -> i64 {
+    let grid = expect_valid(parse_input(file));
+    let regions = find_regions(&grid);
+    let price: i32 = regions
+        .iter()
+        .map(|region| region.area() as i32 * count_region_sides(&regions, region))
+        .sum();
+    price as i64
+}
+
+/// The garden with each region in its own colour
+pub fn render(file: &str) -> Canvas {
+    let grid = expect_valid(parse_input(file));
+    let mut canvas = Canvas::from_chars(&grid);
+    canvas.regions(&find_regions(&grid));
+    canvas
+}
+
+pub struct Solver;
+
+impl Solution for Solver {
+    type Params = ();
+
+    fn parse(input: &str) -> Result<(), ParseError> {
+        black_box(parse_input(input))?;
+        Ok(())
+    }
+
+    fn part_one(input: &str, _: &()) -> Solved {
+        Ok(part_one(input).into())
+    }
+
+    fn part_two(input: &str, _: &()) -> Solved {
+        Ok(part_two(input).into())
+    }
+
+    fn render(input: &str, _: &()) -> Option<Canvas> {
+        Some(render(input))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const BASIC_EXAMPLE: &str = "AAAA
+BBCD
+BBCC
+EEEC";
+
+    const OTHER_EXAMPLE: &str = "OOOOO
+OXOXO
+OOOOO
+OXOXO
+OOOOO";
+
+    const EXAMPLE_DATA: &str = "RRRRIICCFF
+RRRRIICCCF
+VVRRRCCFFF
+VVRCCCJFFF
+VVVVCJJCFE
+VVIVCCJJEE
+VVIIICJJEE
+MIIIIIJJEE
+MIIISIJEEE
+MMMISSJEEE";
+
+    const PART_TWO_BASIC: &str = "EEEEE
+EXXXX
+EEEEE
+EXXXX
+EEEEE";
+
+    #[test]
+    fn test_part_one_basic() {
+        let result = part_one(&String::from(BASIC_EXAMPLE));
+        assert_eq!(result, 140);
+    }
+
+    #[test]
+    fn test_part_one_other() {
+        let result = part_one(&String::from(OTHER_EXAMPLE));
+        assert_eq!(result, 772);
+    }
+
+    #[test]
+    fn test_part_one_as_given() {
+        let result = part_one(&String::from(EXAMPLE_DATA));
+        assert_eq!(result, 1930);
+    }
+
+    #[test]
+    fn test_part_two_basic() {
+        let result = part_two(&String::from(BASIC_EXAMPLE));
+        assert_eq!(result, 80);
+    }
+
+    #[test]
+    fn test_part_two_e() {
+        let result = part_two(&String::from(PART_TWO_BASIC));
+        assert_eq!(result, 236);
+    }
+
+    #[test]
+    fn test_single_large_region() {
+        // Deep enough to overflow the stack with a recursive flood fill
+        let row = "A".repeat(500);
+        let garden = vec![row.as_str(); 500].join("\n");
+        assert_eq!(part_one(&garden), 250_000 * 2000);
+        assert_eq!(part_two(&garden), 250_000 * 4);
+    }
+
+    #[test]
+    fn test_part_two_as_given() {
+        let result = part_two(&String::from(EXAMPLE_DATA));
+        assert_eq!(result, 1206);
+    }
+}
@@ -0,0 +1,134 @@
+//! Day 13: Claw Contraption
+
+use crate::{
+    answer::Solved,
+    number_theory::solve_linear_system,
+    parse::{expect_valid, non_blank_lines, ErrorKind, ParseError},
+    Solution,
+};
+use std::hint::black_box;
+
+pub fn parse_input(file: &str, offset: u64) -> Result<Vec<Vec<(u64, u64)>>, ParseError> {
+    let lines = non_blank_lines(file).collect::<Vec<_>>();
+    let mut machines = Vec::new();
+    for machine in lines.chunks(3) {
+        let [button_a, button_b, prize] = machine else {
+            let last = machine.last().unwrap();
+            return Err(last.error_at(
+                last.text.len(),
+                ErrorKind::Expected(String::from("button A, button B and prize lines")),
+            ));
+        };
+        let [a_x, a_y] = button_a.exact_numbers()?;
+        let [b_x, b_y] = button_b.exact_numbers()?;
+        let [prize_x, prize_y] = prize.exact_numbers::<u64, 2>()?;
+        machines.push(vec![
+            (a_x, a_y),
+            (b_x, b_y),
+            (prize_x + offset, prize_y + offset),
+        ]);
+    }
+    Ok(machines)
+}
+
+/// How many times to press the cheap and the expensive button to reach `target`, when that can be
+/// done at all. If several combinations reach it, the one costing the fewest tokens is chosen
+pub fn integer_solutions(
+    target: (u64, u64),
+    cheap: (u64, u64),
+    expensive: (u64, u64),
+) -> Option<(u64, u64)> {
+    let signed = |value: u64| i64::try_from(value).expect("claw values should fit in an i64");
+    let solutions = solve_linear_system([
+        (signed(cheap.0), signed(expensive.0), signed(target.0)),
+        (signed(cheap.1), signed(expensive.1), signed(target.1)),
+    ])
+    .expect("button presses should fit in a u64");
+    solutions.cheapest((1, 3))
+}
+
+pub fn part_one(file: &str) -> u64 {
+    let machines = expect_valid(parse_input(file, 0));
+
+    let mut winning_token_costs = 0;
+    for machine in machines {
+        let multipliers = integer_solutions(machine[2], machine[1], machine[0]);
+        if let Some((n_cheap, n_expensive)) = multipliers {
+            winning_token_costs += n_cheap + n_expensive * 3;
+        }
+    }
+    winning_token_costs
+}
+
+pub fn part_two(file: &str) -> u64 {
+    let machines = expect_valid(parse_input(file, 10_000_000_000_000u64));
+
+    let mut winning_token_costs = 0;
+    for machine in machines {
+        let multipliers = integer_solutions(machine[2], machine[1], machine[0]);
+        if let Some((n_cheap, n_expensive)) = multipliers {
+            winning_token_costs += n_cheap + n_expensive * 3;
+        }
+    }
+    winning_token_costs
+}
+
+pub struct Solver;
+
+impl Solution for Solver {
+    type Params = ();
+
+    fn parse(input: &str) -> Result<(), ParseError> {
+        black_box(parse_input(input, 0))?;
+        Ok(())
+    }
+
+    fn part_one(input: &str, _: &()) -> Solved {
+        Ok(part_one(input).into())
+    }
+
+    fn part_two(input: &str, _: &()) -> Solved {
+        Ok(part_two(input).into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE_DATA: &str = "Button A: X+94, Y+34
+Button B: X+22, Y+67
+Prize: X=8400, Y=5400
+
+Button A: X+26, Y+66
+Button B: X+67, Y+21
+Prize: X=12748, Y=12176
+
+Button A: X+17, Y+86
+Button B: X+84, Y+37
+Prize: X=7870, Y=6450
+
+Button A: X+69, Y+23
+Button B: X+27, Y+71
+Prize: X=18641, Y=10279";
+
+    #[test]
+    fn test_part_one_as_given() {
+        let result = part_one(&String::from(EXAMPLE_DATA));
+        assert_eq!(result, 480);
+    }
+
+    #[test]
+    fn test_part_two_as_given() {
+        let result = part_two(&String::from(EXAMPLE_DATA));
+        assert_eq!(result, 875318608908);
+    }
+
+    #[test]
+    fn test_collinear_buttons() {
+        // Both buttons move along the same line, so the prize can be reached several ways
+        assert_eq!(integer_solutions((8, 8), (1, 1), (4, 4)), Some((0, 2)));
+        assert_eq!(integer_solutions((10, 10), (2, 2), (5, 5)), Some((5, 0)));
+        assert_eq!(integer_solutions((10, 11), (1, 1), (2, 2)), None);
+    }
+}
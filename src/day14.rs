@@ -0,0 +1,323 @@
+//! Day 14: Restroom Redoubt
+
+use crate::{
+    animation::Recorder,
+    answer::{SolveError, Solved},
+    debug,
+    number_theory::{lcm, solve_congruence, Congruence},
+    params::{positive, ParamError, Parameters, Settings},
+    parse::{expect_valid, non_blank_lines, ParseError},
+    render::{Canvas, Colour},
+    warn, Solution,
+};
+use std::hint::black_box;
+
+pub fn parse_input(file: &str) -> Result<Vec<(i32, i32, i32, i32)>, ParseError> {
+    non_blank_lines(file)
+        .map(|line| {
+            let [x, y, vx, vy] = line.exact_numbers()?;
+            Ok((x, y, vx, vy))
+        })
+        .collect()
+}
+
+/// Checks that every robot starts inside a room `width` by `height` tiles
+fn check_room(robots: &[(i32, i32, i32, i32)], width: i32, height: i32) -> Result<(), SolveError> {
+    let outside = robots
+        .iter()
+        .find(|&&(x, y, _, _)| !(0..width).contains(&x) || !(0..height).contains(&y));
+    match outside {
+        Some((x, y, _, _)) => Err(SolveError::NoAnswer(format!(
+            "robot at {x},{y} starts outside a room {width} by {height}"
+        ))),
+        None => Ok(()),
+    }
+}
+
+/// Where a robot is after `seconds`. The room wraps around, so only the remainder of `seconds`
+/// modulo each side matters, which keeps large values from overflowing
+fn position_after(
+    &(x, y, vx, vy): &(i32, i32, i32, i32),
+    width: i32,
+    height: i32,
+    seconds: i32,
+) -> (i32, i32) {
+    let wrap = |position: i32, velocity: i32, size: i32| {
+        let seconds = i64::from(seconds.rem_euclid(size));
+        let moved = i64::from(position) + seconds * i64::from(velocity);
+        moved.rem_euclid(i64::from(size)) as i32
+    };
+    (wrap(x, vx, width), wrap(y, vy, height))
+}
+
+pub fn safety_factor(width: i32, height: i32, file: &str, seconds: i32) -> Result<u64, SolveError> {
+    let robots = expect_valid(parse_input(file));
+    check_room(&robots, width, height)?;
+    let mut quadrants = vec![vec!(0u64; 2); 2];
+    for robot in robots {
+        let (final_x, final_y) = position_after(&robot, width, height, seconds);
+        if final_x == width / 2 || final_y == height / 2 {
+            continue;
+        }
+        let quadrant_x = if final_x > (width / 2) { 1 } else { 0 };
+        let quadrant_y = if final_y > (height / 2) { 1 } else { 0 };
+        quadrants[quadrant_x][quadrant_y] += 1;
+    }
+    Ok(quadrants[0][0] * quadrants[0][1] * quadrants[1][0] * quadrants[1][1])
+}
+
+/// The seconds at which two robots share a tile, as a congruence modulo the room's period. The
+/// x coordinates meet on one congruence modulo the width and the y coordinates on another modulo
+/// the height, and the Chinese remainder theorem joins them
+fn collision_times(
+    first: (i32, i32, i32, i32),
+    second: (i32, i32, i32, i32),
+    width: i32,
+    height: i32,
+) -> Option<Congruence> {
+    let meet = |position: i32, other_position: i32, velocity: i32, other_velocity: i32, size| {
+        solve_congruence(
+            i64::from(velocity) - i64::from(other_velocity),
+            i64::from(other_position) - i64::from(position),
+            i64::from(size),
+        )
+    };
+    let along_x = meet(first.0, second.0, first.2, second.2, width)?;
+    let along_y = meet(first.1, second.1, first.3, second.3, height)?;
+    along_x.combine(along_y)
+}
+
+/// The longest room period searched for a picture, as the search keeps a flag for every second
+const MAX_PERIOD: usize = 10_000_000;
+
+/// The first second at which the robots draw their picture
+fn picture_second(
+    robots: &[(i32, i32, i32, i32)],
+    width: i32,
+    height: i32,
+) -> Result<usize, SolveError> {
+    check_room(robots, width, height)?;
+    // The robots are all back where they started after this many seconds
+    let period = lcm(width.into(), height.into()).expect("room period should fit in an i64");
+    let period = usize::try_from(period).expect("room period should fit in a usize");
+    if period > MAX_PERIOD {
+        return Err(SolveError::NoAnswer(format!(
+            "a room {width} by {height} only repeats every {period} seconds, too long to search"
+        )));
+    }
+    let mut crowded = vec![false; period];
+    for (i, &first) in robots.iter().enumerate() {
+        for &second in &robots[i + 1..] {
+            if let Some(times) = collision_times(first, second, width, height) {
+                for second in (times.residue as usize..period).step_by(times.modulus as usize) {
+                    crowded[second] = true;
+                }
+            }
+        }
+    }
+
+    // Assumed criterion: the picture is drawn at the first second when no two robots share a tile
+    (1..=period)
+        .find(|&second| !crowded[second % period])
+        .ok_or_else(|| SolveError::NoAnswer(String::from("the robots never draw a picture")))
+}
+
+/// The room after `seconds`, with an `X` on every tile that has a robot
+pub fn draw_robots(
+    robots: &[(i32, i32, i32, i32)],
+    width: i32,
+    height: i32,
+    seconds: i32,
+) -> Canvas {
+    let tiles = robots.iter().map(|robot| {
+        let (x, y) = position_after(robot, width, height, seconds);
+        (x as usize, y as usize)
+    });
+    let mut canvas = Canvas::blank(width as usize, height as usize);
+    canvas.mark(tiles, 'X', Some(Colour::GREEN));
+    canvas
+}
+
+pub fn find_picture(width: i32, height: i32, file: &str) -> Result<u64, SolveError> {
+    let robots = expect_valid(parse_input(file));
+    let it = picture_second(&robots, width, height)?;
+    debug!(
+        "the robots draw a picture after {it} seconds:\n{}",
+        draw_robots(&robots, width, height, it as i32).to_text()
+    );
+    Ok(it as u64)
+}
+
+/// The picture the robots draw
+pub fn render(width: i32, height: i32, file: &str) -> Result<Canvas, SolveError> {
+    let robots = expect_valid(parse_input(file));
+    let seconds = picture_second(&robots, width, height)?;
+    Ok(draw_robots(&robots, width, height, seconds as i32))
+}
+
+/// Records the room every second until the robots draw their picture
+pub fn animate(
+    width: i32,
+    height: i32,
+    file: &str,
+    recorder: &mut Recorder,
+) -> Result<(), SolveError> {
+    let robots = expect_valid(parse_input(file));
+    let seconds = picture_second(&robots, width, height)? as i32;
+    for second in 0..=seconds {
+        recorder.record(|| draw_robots(&robots, width, height, second));
+    }
+    recorder.finish(|| draw_robots(&robots, width, height, seconds));
+    Ok(())
+}
+
+/// Size of the room, and how many seconds to wait before measuring the safety factor
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Params {
+    pub width: i32,
+    pub height: i32,
+    pub seconds: i32,
+}
+
+impl Default for Params {
+    fn default() -> Params {
+        Params {
+            width: 101,
+            height: 103,
+            seconds: 100,
+        }
+    }
+}
+
+impl Parameters for Params {
+    fn configure(settings: &Settings) -> Result<Params, ParamError> {
+        settings.only(&["width", "height", "seconds"])?;
+        let defaults = Params::default();
+        Ok(Params {
+            width: positive("width", settings.get_or("width", defaults.width)?)?,
+            height: positive("height", settings.get_or("height", defaults.height)?)?,
+            seconds: settings.get_or("seconds", defaults.seconds)?,
+        })
+    }
+}
+
+pub fn part_one(input: &str) -> Result<u64, SolveError> {
+    let params = Params::default();
+    safety_factor(params.width, params.height, input, params.seconds)
+}
+
+pub fn part_two(input: &str) -> Result<u64, SolveError> {
+    let params = Params::default();
+    find_picture(params.width, params.height, input)
+}
+
+pub struct Solver;
+
+impl Solution for Solver {
+    type Params = Params;
+
+    fn parse(input: &str) -> Result<(), ParseError> {
+        black_box(parse_input(input))?;
+        Ok(())
+    }
+
+    fn part_one(input: &str, params: &Params) -> Solved {
+        Ok(safety_factor(params.width, params.height, input, params.seconds)?.into())
+    }
+
+    fn part_two(input: &str, params: &Params) -> Solved {
+        Ok(find_picture(params.width, params.height, input)?.into())
+    }
+
+    fn render(input: &str, params: &Params) -> Option<Canvas> {
+        render(params.width, params.height, input)
+            .inspect_err(|error| warn!("{error}"))
+            .ok()
+    }
+
+    fn animate(input: &str, params: &Params, recorder: &mut Recorder) -> bool {
+        animate(params.width, params.height, input, recorder)
+            .inspect_err(|error| warn!("{error}"))
+            .is_ok()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE_DATA: &str = "p=0,4 v=3,-3
+p=6,3 v=-1,-3
+p=10,3 v=-1,2
+p=2,0 v=2,-1
+p=0,0 v=1,3
+p=3,0 v=-2,-2
+p=7,6 v=-1,-3
+p=3,0 v=-1,-2
+p=9,3 v=2,3
+p=7,3 v=-1,2
+p=2,4 v=2,-3
+p=9,5 v=-3,-3";
+
+    #[test]
+    fn test_part_one_as_given() {
+        let result = safety_factor(11, 7, &String::from(EXAMPLE_DATA), 100);
+        assert_eq!(result, Ok(12));
+    }
+
+    #[test]
+    fn test_room_too_small() {
+        assert_eq!(
+            safety_factor(5, 5, EXAMPLE_DATA, 100),
+            Err(SolveError::NoAnswer(String::from(
+                "robot at 6,3 starts outside a room 5 by 5"
+            )))
+        );
+        assert!(find_picture(5, 5, EXAMPLE_DATA).is_err());
+        // Two robots moving together share a tile every second
+        let twins = "p=0,0 v=1,1\np=0,0 v=1,1\n";
+        assert_eq!(
+            find_picture(3, 3, twins),
+            Err(SolveError::NoAnswer(String::from(
+                "the robots never draw a picture"
+            )))
+        );
+    }
+
+    #[test]
+    fn test_large_parameters() {
+        assert_eq!(
+            safety_factor(11, 7, EXAMPLE_DATA, 2_000_000_000),
+            safety_factor(11, 7, EXAMPLE_DATA, 2_000_000_000 % 77)
+        );
+        assert_eq!(
+            find_picture(100_000, 100_003, EXAMPLE_DATA),
+            Err(SolveError::NoAnswer(String::from(
+                "a room 100000 by 100003 only repeats every 10000300000 seconds, too long to search"
+            )))
+        );
+    }
+
+    #[test]
+    fn test_picture_matches_simulation() {
+        let robots = parse_input(EXAMPLE_DATA).unwrap();
+        let first_uncrowded = (1..)
+            .find(|&seconds| {
+                let tiles = robots
+                    .iter()
+                    .map(|&(x, y, vx, vy)| {
+                        (
+                            (x + seconds * vx).rem_euclid(11),
+                            (y + seconds * vy).rem_euclid(7),
+                        )
+                    })
+                    .collect::<std::collections::HashSet<_>>();
+                tiles.len() == robots.len()
+            })
+            .unwrap();
+        assert_eq!(
+            find_picture(11, 7, EXAMPLE_DATA),
+            Ok(first_uncrowded as u64)
+        );
+    }
+}
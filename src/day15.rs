@@ -0,0 +1,391 @@
+//! Day 15: Warehouse Woes
+
+use crate::{
+    animation::Recorder,
+    answer::Solved,
+    geometry::{Direction, Point},
+    grid::{Grid, Position},
+    parse::{expect_valid, marked_char_grid, sections_of, ParseError},
+    render::{Canvas, Cell, Colour},
+    Solution,
+};
+use std::hint::black_box;
+
+pub fn parse_input(file: &str) -> Result<(Grid<char>, Vec<Direction>), ParseError> {
+    let [warehouse, moves] = sections_of(file, "warehouse, then moves")?;
+    let grid = marked_char_grid(warehouse, &['@'])?;
+    let mut instructions = Vec::new();
+    for line in moves {
+        instructions.extend(line.trim().cells("one of ^>v<", Direction::from_arrow)?);
+    }
+    Ok((grid, instructions))
+}
+
+pub fn interpret_double_warehouse(warehouse: &Grid<char>) -> Grid<char> {
+    let rows = warehouse
+        .rows()
+        .map(|row| {
+            row.iter()
+                .flat_map(|&c| match c {
+                    'O' => ['[', ']'],
+                    '@' => ['@', '.'],
+                    _c => [_c, _c],
+                })
+                .collect()
+        })
+        .collect();
+    Grid::from_rows(rows)
+}
+
+pub fn get_move(robot_pos: Position, direction: Direction) -> Position {
+    (Point::from(robot_pos) + direction.vector())
+        .position()
+        .expect("walls should stop every move off the map")
+}
+pub fn test_move(grid: &Grid<char>, robot_pos: Position, direction: Direction) -> char {
+    grid[get_move(robot_pos, direction)]
+}
+
+pub fn push_box_vertical(
+    grid: &Grid<char>,
+    starting_pos: Position,
+    direction: Direction,
+) -> Option<Vec<Position>> {
+    let c = grid[starting_pos];
+    if c != '[' && c != ']' {
+        // This isn't a box
+        return Some(Vec::new());
+    }
+    let v = direction.vector();
+    let other_box_half_offset: isize = if c == '[' { 1 } else { -1 };
+    let left_pos = if c == '[' {
+        starting_pos
+    } else {
+        (starting_pos.0 - 1, starting_pos.1)
+    };
+    let above_pos = (starting_pos.0, (starting_pos.1 as isize + v.y) as usize);
+    let above = grid[above_pos];
+    let other_pos = (
+        (above_pos.0 as isize + other_box_half_offset) as usize,
+        above_pos.1,
+    );
+    let other = grid[other_pos];
+    if other == '#' || above == '#' {
+        return None;
+    }
+    if above == c {
+        return match push_box_vertical(grid, (left_pos.0, above_pos.1), direction) {
+            None => None,
+            Some(mut x) => {
+                x.push(left_pos);
+                return Some(x);
+            }
+        };
+    }
+    let mut boxes = Vec::new();
+    if above == '[' || above == ']' {
+        match push_box_vertical(grid, above_pos, direction) {
+            None => {
+                return None;
+            }
+            Some(x) => boxes.extend(x),
+        }
+    }
+    if other == '[' || other == ']' {
+        match push_box_vertical(grid, other_pos, direction) {
+            None => {
+                return None;
+            }
+            Some(x) => boxes.extend(x),
+        }
+    }
+    boxes.push(left_pos);
+    Some(boxes)
+}
+
+pub fn push_box(
+    grid: &Grid<char>,
+    box_pos: Position,
+    direction: Direction,
+) -> Option<Vec<Position>> {
+    // Try to push the box at this position in this direction.
+    // Returns whether the box was pushed and the position of the box / end box in the row
+
+    let boxes = ['O', '[', ']'];
+    if grid[box_pos] == 'O' || direction.is_horizontal() {
+        let mut crawler = box_pos;
+        while boxes.contains(&grid[crawler]) {
+            crawler = get_move(crawler, direction);
+        }
+        if grid[crawler] == '#' {
+            return None;
+        }
+        if grid[box_pos] == 'O' {
+            return Some(vec![crawler]);
+        } else {
+            let n = (crawler.0.abs_diff(box_pos.0) / 2) as isize;
+            let mut b = Vec::new();
+            let v = direction.vector();
+            let offset = if v.x > 0 { 0 } else { -1 };
+            for x in 1..=n {
+                b.push((
+                    (crawler.0 as isize - 2 * v.x * x + offset) as usize,
+                    crawler.1,
+                ));
+            }
+            return Some(b);
+        }
+    }
+    push_box_vertical(grid, box_pos, direction)
+}
+
+pub fn get_gps(grid: &Grid<char>) -> i64 {
+    let sum_of_gps: usize = grid
+        .iter()
+        .filter(|(_, &c)| c == 'O' || c == '[')
+        .map(|((x, y), _)| x + 100 * y)
+        .sum();
+    sum_of_gps as i64
+}
+
+pub fn find_robot(grid: &Grid<char>) -> Position {
+    grid.find(&'@').expect("Couldn't find robot!")
+}
+
+/// Moves the robot one step in the single-width warehouse, pushing any boxes in the way, and
+/// returns where it ends up
+pub fn move_single(grid: &mut Grid<char>, robot_pos: Position, direction: Direction) -> Position {
+    // Fortunately walls always block robot escape
+    match test_move(grid, robot_pos, direction) {
+        '#' => robot_pos, // Do nothing
+        '.' => {
+            // Just move there
+            grid[robot_pos] = '.';
+            let robot_pos = get_move(robot_pos, direction);
+            grid[robot_pos] = '@';
+            robot_pos
+        }
+        'O' => {
+            let box_pos = get_move(robot_pos, direction);
+            let pushed = push_box(grid, box_pos, direction);
+            match pushed {
+                Some(pushed) => {
+                    let pushed_pos = pushed[0];
+                    grid[box_pos] = '@';
+                    grid[robot_pos] = '.';
+                    grid[pushed_pos] = 'O';
+                    box_pos
+                }
+                None => robot_pos,
+            }
+        }
+        _ => panic!("Unrecognised character"),
+    }
+}
+
+/// Moves the robot one step in the double-width warehouse, pushing any boxes in the way, and
+/// returns where it ends up
+pub fn move_double(grid: &mut Grid<char>, robot_pos: Position, direction: Direction) -> Position {
+    // Fortunately walls always block robot escape
+    match test_move(grid, robot_pos, direction) {
+        '#' => robot_pos, // Do nothing
+        '.' => {
+            // Just move there
+            grid[robot_pos] = '.';
+            let robot_pos = get_move(robot_pos, direction);
+            grid[robot_pos] = '@';
+            robot_pos
+        }
+        '[' | ']' => {
+            let box_pos = get_move(robot_pos, direction);
+            let pushed = push_box(grid, box_pos, direction);
+            match pushed {
+                Some(boxes) => {
+                    for block in &boxes {
+                        // Remove previous blockes
+                        grid[*block] = '.';
+                        grid[(block.0 + 1, block.1)] = '.';
+                    }
+                    for block in &boxes {
+                        // Move up
+                        let moved = get_move(*block, direction);
+                        grid[moved] = '[';
+                        grid[(moved.0 + 1, moved.1)] = ']';
+                    }
+                    grid[box_pos] = '@';
+                    grid[robot_pos] = '.';
+                    box_pos
+                }
+                None => robot_pos,
+            }
+        }
+        _ => panic!("Unrecognised character"),
+    }
+}
+
+/// The warehouse after the robot has made every move, using `step` to make each one
+pub fn run_robot<F>(mut grid: Grid<char>, instructions: &[Direction], mut step: F) -> Grid<char>
+where
+    F: FnMut(&mut Grid<char>, Position, Direction) -> Position,
+{
+    let mut robot_pos = find_robot(&grid);
+    for &direction in instructions {
+        robot_pos = step(&mut grid, robot_pos, direction);
+    }
+    grid
+}
+
+pub fn part_one(file: &str) -> i64 {
+    let (grid, instructions) = expect_valid(parse_input(file));
+    get_gps(&run_robot(grid, &instructions, move_single))
+}
+
+pub fn part_two(file: &str) -> i64 {
+    let (warehouse, instructions) = expect_valid(parse_input(file));
+    let grid = interpret_double_warehouse(&warehouse);
+    get_gps(&run_robot(grid, &instructions, move_double))
+}
+
+/// Colours a warehouse map: walls grey, boxes orange and the robot red
+pub fn draw_warehouse(grid: &Grid<char>) -> Canvas {
+    Canvas::new(grid, |&c| Cell {
+        foreground: match c {
+            '#' => Some(Colour::GREY),
+            'O' | '[' | ']' => Some(Colour::ORANGE),
+            '@' => Some(Colour::RED),
+            _ => None,
+        },
+        ..Cell::plain(c)
+    })
+}
+
+/// The double-width warehouse once the robot has finished
+pub fn render(file: &str) -> Canvas {
+    let (warehouse, instructions) = expect_valid(parse_input(file));
+    let grid = interpret_double_warehouse(&warehouse);
+    draw_warehouse(&run_robot(grid, &instructions, move_double))
+}
+
+/// Records the double-width warehouse after each of the robot's moves
+pub fn animate(file: &str, recorder: &mut Recorder) {
+    let (warehouse, instructions) = expect_valid(parse_input(file));
+    let grid = interpret_double_warehouse(&warehouse);
+    recorder.record(|| draw_warehouse(&grid));
+    let grid = run_robot(grid, &instructions, |grid, robot_pos, direction| {
+        let robot_pos = move_double(grid, robot_pos, direction);
+        recorder.record(|| draw_warehouse(grid));
+        robot_pos
+    });
+    recorder.finish(|| draw_warehouse(&grid));
+}
+
+pub struct Solver;
+
+impl Solution for Solver {
+    type Params = ();
+
+    fn parse(input: &str) -> Result<(), ParseError> {
+        black_box(parse_input(input))?;
+        Ok(())
+    }
+
+    fn part_one(input: &str, _: &()) -> Solved {
+        Ok(part_one(input).into())
+    }
+
+    fn part_two(input: &str, _: &()) -> Solved {
+        Ok(part_two(input).into())
+    }
+
+    fn render(input: &str, _: &()) -> Option<Canvas> {
+        Some(render(input))
+    }
+
+    fn animate(input: &str, _: &(), recorder: &mut Recorder) -> bool {
+        animate(input, recorder);
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE_DATA: &str = "##########
+#..O..O.O#
+#......O.#
+#.OO..O.O#
+#..O@..O.#
+#O#..O...#
+#O..O..O.#
+#.OO.O.OO#
+#....O...#
+##########
+
+<vv>^<v^>v>^vv^v>v<>v^v<v<^vv<<<^><<><>>v<vvv<>^v^>^<<<><<v<<<v^vv^v>^
+vvv<<^>^v^^><<>>><>^<<><^vv^^<>vvv<>><^^v>^>vv<>v<<<<v<^v>^<^^>>>^<v<v
+><>vv>v^v^<>><>>>><^^>vv>v<^^^>>v^v^<^^>v^^>v^<^v>v<>>v^v^<v>v^^<^^vv<
+<<v<^>>^^^^>>>v^<>vvv^><v<<<>^^^vv^<vvv>^>v<^^^^v<>^>vvvv><>>v^<<^^^^^
+^><^><>>><>^^<<^^v>>><^<v>^<vv>>v>>>^v><>^v><<<<v>>v<v<v>vvv>^<><<>^><
+^>><>^v<><^vvv<^^<><v<<<<<><^v<<<><<<^^<v<^^^><^>>^<v^><<<^>>^v<v^v<v^
+>^>>^v>vv>^<<^v<>><<><<v<<v><>v<^vv<<<>^^v^>^^>>><<^v>>v^v><^^>>^<>vv^
+<><^^>^^^<><vvvvv^v<v<<>^v<v>v<<^><<><<><<<^^<<<^<<>><<><^^^>^^<>^>v<>
+^^>vv<^v^v<vv>^<><v<^v>^^^>>>^^vvv^>vvv<>>>^<^>>>>>^<<^v>^vvv<>^<><<v>
+v^^>>><<^^<>>^v^<v^vv<>v^<<>^<^v^v><^<<<><<^<v><v<>vv>>v><v^<vv<>v^<<^";
+
+    const SMALL_EXAMPLE: &str = "########
+#..O.O.#
+##@.O..#
+#...O..#
+#.#.O..#
+#...O..#
+#......#
+########
+
+<^^>>>vv<v>>v<<";
+
+    #[test]
+    fn test_part_one_as_given_large() {
+        let result = part_one(&String::from(EXAMPLE_DATA));
+        assert_eq!(result, 10092);
+    }
+
+    #[test]
+    fn test_part_one_as_given_small() {
+        let result = part_one(&String::from(SMALL_EXAMPLE));
+        assert_eq!(result, 2028);
+    }
+
+    #[test]
+    fn test_part_two_as_given() {
+        let result = part_two(&String::from(EXAMPLE_DATA));
+        assert_eq!(result, 9021);
+    }
+
+    #[test]
+    fn test_bad_move_is_reported() {
+        let error = parse_input("#####\n#@.O#\n#####\n\n<<\n^x>\n").unwrap_err();
+        assert_eq!((error.line, error.column), (6, 2));
+    }
+
+    #[test]
+    fn test_bad_warehouse_is_reported() {
+        let ragged = parse_input("#####\n#@.O#\n####\n\n<<\n").unwrap_err();
+        assert_eq!((ragged.line, ragged.column), (3, 5));
+        let no_robot = parse_input("#####\n#..O#\n#####\n\n<<\n").unwrap_err();
+        assert_eq!(
+            no_robot.to_string(),
+            "line 3, column 6: expected a '@' in the grid"
+        );
+    }
+
+    #[test]
+    fn test_animation_ends_on_final_warehouse() {
+        let mut recorder = Recorder::new(4);
+        animate(SMALL_EXAMPLE, &mut recorder);
+        // The starting warehouse and one step per move, of which every fourth is kept plus the last
+        assert_eq!(recorder.steps(), 16);
+        assert_eq!(recorder.frames().len(), 5);
+        assert_eq!(recorder.frames().last(), Some(&render(SMALL_EXAMPLE)));
+    }
+}
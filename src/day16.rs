@@ -0,0 +1,253 @@
+//! Day 16: Reindeer Maze
+
+use crate::{
+    answer::{SolveError, Solved},
+    geometry::Direction,
+    grid::{Grid, Position},
+    parse::{expect_valid, marked_char_grid, non_blank_lines, ParseError},
+    pathfinding::{dijkstra, Search},
+    render::{Canvas, Colour},
+    svg::{Drawing, Figure},
+    warn, Solution,
+};
+use std::{collections::HashSet, hint::black_box};
+
+pub fn parse_input(file: &str) -> Result<Grid<char>, ParseError> {
+    marked_char_grid(non_blank_lines(file), &['S', 'E'])
+}
+
+pub fn find_start_and_end(grid: &Grid<char>) -> (Position, Position) {
+    let start = grid.find(&'S').expect("parsing checks for a start");
+    let end = grid.find(&'E').expect("parsing checks for an end");
+    (start, end)
+}
+
+/// Where the reindeer is and which way it is facing
+pub type Reindeer = (Position, Direction);
+
+/// Finds the cheapest score to every reachable tile and facing, starting on `S` facing east. Moving
+/// forward costs 1 point and turning a quarter turn in place costs 1000
+pub fn search_maze(grid: &Grid<char>) -> Search<Reindeer> {
+    let (start, _end) = find_start_and_end(grid);
+    dijkstra([(start, Direction::Right)], |&(position, facing)| {
+        let forward = grid
+            .step(position, facing)
+            .filter(|&ahead| grid[ahead] != '#')
+            .map(|ahead| ((ahead, facing), 1));
+        let turns = [facing.clockwise(), facing.counter_clockwise()]
+            .map(|turned| ((position, turned), 1000));
+        forward.into_iter().chain(turns)
+    })
+}
+
+fn end_states(end: Position) -> [Reindeer; 4] {
+    Direction::ALL.map(|facing| (end, facing))
+}
+
+fn unreachable() -> SolveError {
+    SolveError::NoAnswer(String::from("the end can't be reached from the start"))
+}
+
+pub fn part_one(file: &str) -> Result<u64, SolveError> {
+    let grid = expect_valid(parse_input(file));
+    let (_start, end) = find_start_and_end(&grid);
+    let search = search_maze(&grid);
+    let (_, score) = search.nearest(&end_states(end)).ok_or_else(unreachable)?;
+    Ok(score)
+}
+
+/// Every tile on at least one of the best paths through the maze
+pub fn best_tiles(grid: &Grid<char>) -> HashSet<Position> {
+    let (_start, end) = find_start_and_end(grid);
+    search_maze(grid)
+        .on_best_paths(&end_states(end))
+        .into_iter()
+        .map(|(position, _)| position)
+        .collect()
+}
+
+pub fn part_two(file: &str) -> Result<i64, SolveError> {
+    let grid = expect_valid(parse_input(file));
+    let tiles = best_tiles(&grid);
+    if tiles.is_empty() {
+        return Err(unreachable());
+    }
+    Ok(tiles.len() as i64)
+}
+
+/// The maze with every tile on a best path highlighted
+pub fn render(file: &str) -> Canvas {
+    let grid = expect_valid(parse_input(file));
+    let mut canvas = Canvas::from_chars(&grid);
+    canvas.highlight(best_tiles(&grid), Colour::BLUE);
+    canvas
+}
+
+/// The maze with one best path drawn over the others, which branch off it in a second colour
+pub fn routes_figure(file: &str) -> Result<Figure, SolveError> {
+    let grid = expect_valid(parse_input(file));
+    let (start, end) = find_start_and_end(&grid);
+    let search = search_maze(&grid);
+    let ends = end_states(end);
+    let (goal, _) = search.nearest(&ends).ok_or_else(unreachable)?;
+
+    let mut route = search
+        .path_to(goal)
+        .expect("the nearest end should have a path")
+        .into_iter()
+        .map(|(position, _)| position)
+        .collect::<Vec<Position>>();
+    route.dedup();
+    let on_best_paths = search.on_best_paths(&ends);
+    let alternatives = on_best_paths.iter().flat_map(|state| {
+        search
+            .predecessors(state)
+            .iter()
+            .filter(|previous| previous.0 != state.0 && on_best_paths.contains(previous))
+            .map(|previous| (previous.0, state.0))
+    });
+
+    let mut drawing = Drawing::new(grid.width(), grid.height());
+    drawing
+        .cells(grid.find_all(&'#'), Colour::GREY)
+        .segments(alternatives, Colour::ORANGE)
+        .route(&route, Colour::BLUE)
+        .dot(start, Colour::GREEN)
+        .dot(end, Colour::RED);
+    Ok(Figure {
+        name: "routes",
+        drawing,
+    })
+}
+
+pub struct Solver;
+
+impl Solution for Solver {
+    type Params = ();
+
+    fn parse(input: &str) -> Result<(), ParseError> {
+        black_box(parse_input(input))?;
+        Ok(())
+    }
+
+    fn part_one(input: &str, _: &()) -> Solved {
+        Ok(part_one(input)?.into())
+    }
+
+    fn part_two(input: &str, _: &()) -> Solved {
+        Ok(part_two(input)?.into())
+    }
+
+    fn render(input: &str, _: &()) -> Option<Canvas> {
+        Some(render(input))
+    }
+
+    fn figures(input: &str, _: &()) -> Vec<Figure> {
+        routes_figure(input)
+            .inspect_err(|error| warn!("{error}"))
+            .into_iter()
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE_DATA: &str = "###############
+#.......#....E#
+#.#.###.#.###.#
+#.....#.#...#.#
+#.###.#####.#.#
+#.#.#.......#.#
+#.#.#####.###.#
+#...........#.#
+###.#.#####.#.#
+#...#.....#.#.#
+#.#.#.###.#.#.#
+#.....#...#.#.#
+#.###.#.#.#.#.#
+#S..#.....#...#
+###############";
+
+    const SECOND_EXAMPLE: &str = "#################
+#...#...#...#..E#
+#.#.#.#.#.#.#.#.#
+#.#.#.#...#...#.#
+#.#.#.#.###.#.#.#
+#...#.#.#.....#.#
+#.#.#.#.#.#####.#
+#.#...#.#.#.....#
+#.#.#####.#.###.#
+#.#.#.......#...#
+#.#.###.#####.###
+#.#.#...#.....#.#
+#.#.#.#####.###.#
+#.#.#.........#.#
+#.#.#.#########.#
+#S#.............#
+#################";
+
+    #[test]
+    fn test_part_one_as_given() {
+        let result = part_one(&String::from(EXAMPLE_DATA));
+        assert_eq!(result, Ok(7036));
+    }
+
+    #[test]
+    fn test_part_one_second_example() {
+        let result = part_one(&String::from(SECOND_EXAMPLE));
+        assert_eq!(result, Ok(11048));
+    }
+
+    #[test]
+    fn test_part_two_as_given() {
+        let result = part_two(&String::from(EXAMPLE_DATA));
+        assert_eq!(result, Ok(45));
+    }
+
+    #[test]
+    fn test_part_two_second_example() {
+        let result = part_two(&String::from(SECOND_EXAMPLE));
+        assert_eq!(result, Ok(64));
+    }
+
+    #[test]
+    fn test_render_highlights_best_tiles() {
+        let canvas = render(EXAMPLE_DATA);
+        let highlighted = canvas
+            .cells()
+            .iter()
+            .filter(|(_, cell)| cell.background.is_some());
+        assert_eq!(highlighted.count(), 45);
+    }
+
+    #[test]
+    fn test_routes_figure_shows_alternatives() {
+        let figure = routes_figure(EXAMPLE_DATA).unwrap();
+        assert_eq!(figure.name, "routes");
+        let svg = figure.drawing.to_svg();
+        let alternatives = svg.lines().find(|line| line.starts_with("<path")).unwrap();
+        // There is more than one best path, so some stretches are off the drawn route
+        assert!(alternatives.matches('M').count() > 0);
+        let route = svg
+            .lines()
+            .find(|line| line.starts_with("<polyline"))
+            .unwrap();
+        // The route runs from S to E, taking 36 steps
+        assert_eq!(route.matches(',').count(), 37);
+    }
+
+    #[test]
+    fn test_missing_or_unreachable_end() {
+        let error = parse_input("#####\n#S..#\n#####\n").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 3, column 6: expected a 'E' in the grid"
+        );
+        let walled_off = "#####\n#S#E#\n#####\n";
+        assert_eq!(part_one(walled_off), Err(unreachable()));
+        assert_eq!(part_two(walled_off), Err(unreachable()));
+        assert!(routes_figure(walled_off).is_err());
+    }
+}
@@ -0,0 +1,370 @@
+//! Day 18: RAM Run
+
+use crate::{
+    answer::{SolveError, Solved},
+    grid::{Grid, Position},
+    params::{positive, ParamError, Parameters, Settings},
+    parse::{expect_valid, non_blank_lines, ErrorKind, ParseError},
+    pathfinding::{bfs_in, Tree},
+    regions::{Connectivity, Regions},
+    render::Colour,
+    svg::{Drawing, Figure},
+    union_find::UnionFind,
+    warn, Solution,
+};
+use std::hint::black_box;
+
+pub fn parse_input(file: &str) -> Result<Vec<Position>, ParseError> {
+    non_blank_lines(file)
+        .map(|line| {
+            let coordinates = line.integer_list::<usize>(",")?;
+            match coordinates[..] {
+                [x, y] => Ok((x, y)),
+                _ => Err(line.error(ErrorKind::Expected(String::from("an X,Y coordinate")))),
+            }
+        })
+        .collect()
+}
+
+/// Checks that every byte falls inside a memory space `width` cells square
+pub fn check_bytes(falling_bytes: &[Position], width: usize) -> Result<(), SolveError> {
+    match falling_bytes
+        .iter()
+        .find(|&&(x, y)| x >= width || y >= width)
+    {
+        Some((x, y)) => Err(SolveError::NoAnswer(format!(
+            "byte {x},{y} falls outside a memory space {width} wide"
+        ))),
+        None => Ok(()),
+    }
+}
+
+pub fn fill_grid(falling_bytes: &[Position], width: usize) -> Grid<bool> {
+    let mut grid = Grid::new(width, width, false);
+
+    for &position in falling_bytes {
+        grid[position] = true;
+    }
+    grid
+}
+
+pub type Path = Vec<Position>;
+
+/// Searches outwards from `start` through cells without a fallen byte, stopping once `goal` is
+/// reached
+pub fn explore(
+    grid: &Grid<bool>,
+    start: Position,
+    goal: Position,
+) -> Tree<Position, Grid<Option<Position>>> {
+    bfs_in(
+        grid.map(|_| None),
+        start,
+        |&position| grid.neighbours(position).filter(|&next| !grid[next]),
+        |&position| position == goal,
+    )
+}
+
+fn exit(grid: &Grid<bool>) -> Position {
+    (grid.width() - 1, grid.height() - 1)
+}
+
+/// A shortest path from the top left corner to the bottom right one, or an empty path if fallen
+/// bytes cut them off from each other
+pub fn find_shortest_path(grid: &Grid<bool>) -> Path {
+    explore(grid, (0, 0), exit(grid))
+        .path_to(&exit(grid))
+        .unwrap_or_default()
+}
+
+/// The fewest steps from the top left corner to the bottom right one once `bytes_fallen` bytes, or
+/// all of them if there are fewer, have fallen
+pub fn shortest_path_length(
+    file: &str,
+    width: usize,
+    bytes_fallen: usize,
+) -> Result<u64, SolveError> {
+    let falling_bytes = expect_valid(parse_input(file));
+    let fallen = &falling_bytes[..bytes_fallen.min(falling_bytes.len())];
+    check_bytes(fallen, width)?;
+    let grid = fill_grid(fallen, width);
+    let steps = explore(&grid, (0, 0), exit(&grid)).steps_to(&exit(&grid));
+    let steps = steps
+        .ok_or_else(|| SolveError::NoAnswer(String::from("the fallen bytes cut the exit off")))?;
+    Ok(steps as u64)
+}
+
+/// The byte that first cuts the exit off from the start
+#[derive(Clone, Debug, PartialEq)]
+pub struct Blockage {
+    pub byte: Position,
+    /// How many bytes had fallen, including this one, when the exit was cut off
+    pub bytes_fallen: usize,
+    /// The fallen bytes touching the blocking byte, orthogonally or diagonally, through a chain of
+    /// other fallen bytes. Together they form the wall that separates the start from the exit
+    pub barrier: Vec<Position>,
+}
+
+/// Finds the first byte that disconnects the top left corner from the bottom right one.
+///
+/// Rather than searching again as each byte falls, this starts from the final memory space and
+/// lifts the bytes back out in reverse order, joining each freed cell to its open neighbours in a
+/// union-find. The first byte whose removal reconnects the corners is the one that cut them off
+pub fn find_blockage(falling_bytes: &[Position], width: usize) -> Option<Blockage> {
+    // The first time each cell is hit; later hits on an already corrupted cell change nothing
+    let mut fallen_at = Grid::new(width, width, None);
+    for (i, &byte) in falling_bytes.iter().enumerate() {
+        fallen_at[byte].get_or_insert(i);
+    }
+    let mut sets = UnionFind::new(width * width);
+    for (position, fallen) in fallen_at.iter() {
+        if fallen.is_none() {
+            join_open_neighbours(&fallen_at, &mut sets, position);
+        }
+    }
+    // The top left corner is the first cell and the bottom right the last
+    let (start, exit) = (0, width * width - 1);
+    if sets.connected(start, exit) {
+        return None;
+    }
+
+    for (i, &byte) in falling_bytes.iter().enumerate().rev() {
+        if fallen_at[byte] != Some(i) {
+            continue;
+        }
+        fallen_at[byte] = None;
+        join_open_neighbours(&fallen_at, &mut sets, byte);
+        if sets.connected(start, exit) {
+            return Some(Blockage {
+                byte,
+                bytes_fallen: i + 1,
+                barrier: barrier_through(&falling_bytes[..=i], width, byte),
+            });
+        }
+    }
+    None
+}
+
+fn join_open_neighbours(fallen_at: &Grid<Option<usize>>, sets: &mut UnionFind, position: Position) {
+    for neighbour in fallen_at.neighbours(position) {
+        if fallen_at[neighbour].is_none() {
+            sets.union(
+                fallen_at.cell_index(position),
+                fallen_at.cell_index(neighbour),
+            );
+        }
+    }
+}
+
+/// The fallen bytes joined to `byte` through orthogonal or diagonal contact
+fn barrier_through(falling_bytes: &[Position], width: usize, byte: Position) -> Vec<Position> {
+    let corrupted = fill_grid(falling_bytes, width);
+    let walls = Regions::find(&corrupted, Connectivity::Surrounding, |&a, &b| a && b);
+    walls
+        .region_at(byte)
+        .expect("the byte should be inside the memory space")
+        .cells
+        .clone()
+}
+
+pub fn first_blocking_byte(file: &str, width: usize) -> Result<String, SolveError> {
+    let falling_bytes = expect_valid(parse_input(file));
+    check_bytes(&falling_bytes, width)?;
+    let blockage = find_blockage(&falling_bytes, width)
+        .ok_or_else(|| SolveError::NoAnswer(String::from("no byte cuts the exit off")))?;
+    let (x, y) = blockage.byte;
+    Ok(format!("{x},{y}"))
+}
+
+/// Size of the square memory space, and how many bytes have fallen when the path is measured
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Params {
+    pub width: usize,
+    pub bytes: usize,
+}
+
+impl Default for Params {
+    fn default() -> Params {
+        Params {
+            width: 71,
+            bytes: 1024,
+        }
+    }
+}
+
+impl Parameters for Params {
+    fn configure(settings: &Settings) -> Result<Params, ParamError> {
+        settings.only(&["width", "bytes"])?;
+        let defaults = Params::default();
+        Ok(Params {
+            width: positive("width", settings.get_or("width", defaults.width)?)?,
+            bytes: settings.get_or("bytes", defaults.bytes)?,
+        })
+    }
+}
+
+pub fn part_one(input: &str) -> Result<u64, SolveError> {
+    let params = Params::default();
+    shortest_path_length(input, params.width, params.bytes)
+}
+
+pub fn part_two(input: &str) -> Result<String, SolveError> {
+    let params = Params::default();
+    first_blocking_byte(input, params.width)
+}
+
+/// The shortest path once `bytes_fallen` bytes have fallen, and the wall of bytes that finally
+/// cuts the exit off with the blocking byte marked
+pub fn figures(file: &str, width: usize, bytes_fallen: usize) -> Vec<Figure> {
+    let falling_bytes = expect_valid(parse_input(file));
+    if let Err(error) = check_bytes(&falling_bytes, width) {
+        warn!("{error}");
+        return Vec::new();
+    }
+    let fallen = &falling_bytes[..bytes_fallen.min(falling_bytes.len())];
+    let grid = fill_grid(fallen, width);
+    let mut route = Drawing::new(width, width);
+    route
+        .cells(fallen.iter().copied(), Colour::GREY)
+        .route(&find_shortest_path(&grid), Colour::BLUE)
+        .dot((0, 0), Colour::GREEN)
+        .dot(exit(&grid), Colour::RED);
+    let mut figures = vec![Figure {
+        name: "route",
+        drawing: route,
+    }];
+
+    if let Some(blockage) = find_blockage(&falling_bytes, width) {
+        let mut drawing = Drawing::new(width, width);
+        drawing
+            .cells(
+                falling_bytes[..blockage.bytes_fallen].iter().copied(),
+                Colour::GREY,
+            )
+            .cells(blockage.barrier, Colour::ORANGE)
+            .dot(blockage.byte, Colour::RED);
+        figures.push(Figure {
+            name: "blockage",
+            drawing,
+        });
+    }
+    figures
+}
+
+pub struct Solver;
+
+impl Solution for Solver {
+    type Params = Params;
+
+    fn parse(input: &str) -> Result<(), ParseError> {
+        black_box(parse_input(input))?;
+        Ok(())
+    }
+
+    fn part_one(input: &str, params: &Params) -> Solved {
+        Ok(shortest_path_length(input, params.width, params.bytes)?.into())
+    }
+
+    fn part_two(input: &str, params: &Params) -> Solved {
+        Ok(first_blocking_byte(input, params.width)?.into())
+    }
+
+    fn figures(input: &str, params: &Params) -> Vec<Figure> {
+        figures(input, params.width, params.bytes)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE_DATA: &str = "5,4
+4,2
+4,5
+3,0
+2,1
+6,3
+2,4
+1,5
+0,6
+3,3
+2,6
+5,1
+1,2
+5,5
+2,5
+6,5
+1,4
+0,4
+6,4
+1,1
+6,1
+1,0
+0,5
+1,6
+2,0";
+
+    #[test]
+    fn test_part_one_as_given() {
+        let result = shortest_path_length(&String::from(EXAMPLE_DATA), 7, 12);
+        assert_eq!(result, Ok(22));
+    }
+
+    #[test]
+    fn test_parameters_out_of_range() {
+        // More bytes than the input has are all of them, by which time the exit is cut off
+        let result = shortest_path_length(EXAMPLE_DATA, 7, 1000);
+        assert!(matches!(result, Err(SolveError::NoAnswer(_))));
+        assert_eq!(
+            shortest_path_length(EXAMPLE_DATA, 6, 12),
+            Err(SolveError::NoAnswer(String::from(
+                "byte 6,3 falls outside a memory space 6 wide"
+            )))
+        );
+        assert!(first_blocking_byte(EXAMPLE_DATA, 6).is_err());
+        assert!(figures(EXAMPLE_DATA, 6, 12).is_empty());
+    }
+
+    #[test]
+    fn test_part_two_as_given() {
+        let result = first_blocking_byte(&String::from(EXAMPLE_DATA), 7);
+        assert_eq!(result, Ok(String::from("6,1")));
+    }
+
+    #[test]
+    fn test_blockage_report() {
+        let falling_bytes = parse_input(EXAMPLE_DATA).unwrap();
+        let blockage = find_blockage(&falling_bytes, 7).unwrap();
+        assert_eq!(blockage.byte, (6, 1));
+        assert_eq!(blockage.bytes_fallen, 21);
+        assert!(blockage.barrier.contains(&(6, 1)));
+        assert!(blockage.barrier.len() < blockage.bytes_fallen);
+        assert_eq!(find_blockage(&falling_bytes[..20], 7), None);
+    }
+
+    #[test]
+    fn test_large_grid() {
+        // Two walls across a 1000x1000 space, the first open at the bottom and the second at the
+        // top, so the path has to zig-zag between them
+        let width = 1000;
+        let walls = (0..width - 1)
+            .map(|y| (300, y))
+            .chain((1..width).map(|y| (600, y)))
+            .map(|(x, y)| format!("{x},{y}\n"))
+            .collect::<String>();
+        let bytes = 2 * (width - 1);
+        assert_eq!(shortest_path_length(&walls, width, bytes), Ok(3996));
+
+        let grid = fill_grid(&parse_input(&walls).unwrap(), width);
+        let path = find_shortest_path(&grid);
+        assert_eq!(path.len(), 3997);
+        assert_eq!(path[0], (0, 0));
+        assert!(path.contains(&(600, 0)) && path.contains(&(300, 999)));
+
+        let blocked = format!("{walls}300,999\n");
+        assert_eq!(
+            first_blocking_byte(&blocked, width),
+            Ok(String::from("300,999"))
+        );
+    }
+}
@@ -0,0 +1,131 @@
+//! Day 19: Linen Layout
+
+use crate::{
+    answer::Solved,
+    memo::Memo,
+    parse::{expect_valid, sections_of, ErrorKind, ParseError},
+    Solution,
+};
+use std::{collections::HashSet, hint::black_box};
+
+pub fn parse_available_towels(line: &str) -> (HashSet<&str>, usize) {
+    let mut patterns = HashSet::new();
+    let mut longest = 0usize;
+    let available = line.split(",").map(|x| x.trim());
+    for towel in available {
+        longest = std::cmp::max(longest, towel.len());
+        patterns.insert(towel);
+    }
+    (patterns, longest)
+}
+
+pub fn parse_input(file: &str) -> Result<(HashSet<&str>, usize, Vec<&str>), ParseError> {
+    let [towels, designs] = sections_of(file, "towel patterns, then designs")?;
+    if let Some(extra) = towels.get(1) {
+        return Err(extra.error(ErrorKind::Expected(String::from(
+            "a blank line after the towel patterns",
+        ))));
+    }
+    let (patterns, longest) = parse_available_towels(towels[0].text);
+    let designs = designs.iter().map(|design| design.trim().text).collect();
+    Ok((patterns, longest, designs))
+}
+
+/// How many ways `design` can be made from the towel patterns. Designs share their endings, so
+/// the count for each remaining suffix is remembered in `memo`
+pub fn count_arrangements<'a>(
+    memo: &mut Memo<&'a str, u64>,
+    patterns: &HashSet<&str>,
+    longest: usize,
+    design: &'a str,
+) -> u64 {
+    if design.is_empty() {
+        return 1;
+    }
+    memo.get_or_compute(design, |memo| {
+        let furthest_search = std::cmp::min(longest, design.len());
+        (1..=furthest_search)
+            .filter(|&search_length| patterns.contains(&design[0..search_length]))
+            .map(|search_length| {
+                count_arrangements(memo, patterns, longest, &design[search_length..])
+            })
+            .sum()
+    })
+}
+
+pub fn design_has_matching_pattern(patterns: &HashSet<&str>, longest: usize, design: &str) -> bool {
+    count_arrangements(&mut Memo::new(), patterns, longest, design) > 0
+}
+
+pub fn part_one(file: &str) -> u64 {
+    let (patterns, longest, designs) = expect_valid(parse_input(file));
+    let mut memo = Memo::new();
+    designs
+        .into_iter()
+        .filter(|design| count_arrangements(&mut memo, &patterns, longest, design) > 0)
+        .count() as u64
+}
+
+pub fn part_two(file: &str) -> u64 {
+    let (patterns, longest, designs) = expect_valid(parse_input(file));
+    let mut memo = Memo::new();
+    designs
+        .into_iter()
+        .map(|design| count_arrangements(&mut memo, &patterns, longest, design))
+        .sum()
+}
+
+pub struct Solver;
+
+impl Solution for Solver {
+    type Params = ();
+
+    fn parse(input: &str) -> Result<(), ParseError> {
+        black_box(parse_input(input))?;
+        Ok(())
+    }
+
+    fn part_one(input: &str, _: &()) -> Solved {
+        Ok(part_one(input).into())
+    }
+
+    fn part_two(input: &str, _: &()) -> Solved {
+        Ok(part_two(input).into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE_DATA: &str = "r, wr, b, g, bwu, rb, gb, br
+
+brwrr
+bggr
+gbbr
+rrbgbr
+ubwu
+bwurrg
+brgr
+bbrgwb";
+
+    #[test]
+    fn test_part_one_as_given() {
+        let result = part_one(&String::from(EXAMPLE_DATA));
+        assert_eq!(result, 6);
+    }
+
+    #[test]
+    fn test_part_two_as_given() {
+        let result = part_two(&String::from(EXAMPLE_DATA));
+        assert_eq!(result, 16);
+    }
+
+    #[test]
+    fn test_long_unmatchable_design() {
+        // Exponentially many ways to split the a's, none of which can finish the design
+        let patterns = HashSet::from(["a", "aa", "aaa"]);
+        let design = format!("{}b", "a".repeat(200));
+        assert!(!design_has_matching_pattern(&patterns, 3, &design));
+    }
+}
@@ -0,0 +1,223 @@
+//! Day 20: Race Condition
+
+use crate::{
+    answer::{SolveError, Solved},
+    geometry::{Direction, Point},
+    grid::{Grid, Position},
+    params::{ParamError, Parameters, Settings},
+    parse::{expect_valid, marked_char_grid, non_blank_lines, ParseError},
+    render::Colour,
+    svg::{Drawing, Figure},
+    warn, Solution,
+};
+use std::hint::black_box;
+
+pub fn parse_input(file: &str) -> Result<Grid<char>, ParseError> {
+    marked_char_grid(non_blank_lines(file), &['S', 'E'])
+}
+
+/// The single track from `S` to `E`, failing if it dead-ends before reaching `E`
+pub fn find_racetrack(grid: &Grid<char>) -> Result<Vec<Position>, SolveError> {
+    let mut position = grid.find(&'S').expect("parsing checks for a start");
+
+    let mut previous_direction: Option<Direction> = None;
+    let mut racetrack = vec![position];
+    while grid[position] != 'E' {
+        let (direction, next) = Direction::ALL
+            .into_iter()
+            .filter(|&direction| previous_direction.map(Direction::opposite) != Some(direction))
+            .filter_map(|direction| Some((direction, grid.step(position, direction)?)))
+            .find(|&(_, next)| grid[next] != '#')
+            .ok_or_else(|| {
+                let (x, y) = position;
+                SolveError::NoAnswer(format!("the track dead-ends at {x},{y} before the end"))
+            })?;
+        previous_direction = Some(direction);
+        racetrack.push(next);
+        position = next;
+    }
+    Ok(racetrack)
+}
+
+/// Every cheat of at most `cheat_duration` picoseconds that saves `n` picoseconds or more, as the
+/// racetrack positions where it starts and ends
+pub fn find_cheats(
+    n: usize,
+    cheat_duration: usize,
+    racetrack: &[Position],
+) -> impl Iterator<Item = (Position, Position)> + '_ {
+    (n..racetrack.len()).flat_map(move |i| {
+        let cheat_end = racetrack[i];
+        racetrack[..i - 2]
+            .iter()
+            .enumerate()
+            .filter(move |&(j, &cheat_start)| {
+                let diff = Point::from(cheat_end).manhattan(Point::from(cheat_start));
+                diff <= cheat_duration && i - j - diff >= n
+            })
+            .map(move |(_, &cheat_start)| (cheat_start, cheat_end))
+    })
+}
+
+pub fn find_cheats_of_at_least(n: usize, cheat_duration: usize, racetrack: &[Position]) -> u64 {
+    // Returns the number of cheats that save `n` picoseconds or more
+    find_cheats(n, cheat_duration, racetrack).count() as u64
+}
+
+pub fn count_cheats(file: &str, n: usize, cheat_duration: usize) -> Result<u64, SolveError> {
+    let grid = expect_valid(parse_input(file));
+    let racetrack = find_racetrack(&grid)?;
+    Ok(find_cheats_of_at_least(n, cheat_duration, &racetrack))
+}
+
+/// The fewest picoseconds a cheat has to save to be counted. Every cheat saves at least two, as
+/// it has to go through a wall
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Params {
+    pub threshold: usize,
+}
+
+impl Default for Params {
+    fn default() -> Params {
+        Params { threshold: 100 }
+    }
+}
+
+impl Parameters for Params {
+    fn configure(settings: &Settings) -> Result<Params, ParamError> {
+        settings.only(&["threshold"])?;
+        let threshold = settings.get_or("threshold", Params::default().threshold)?;
+        if threshold < 2 {
+            return Err(ParamError::Invalid {
+                name: String::from("threshold"),
+                reason: String::from("a cheat saves at least 2 picoseconds"),
+            });
+        }
+        Ok(Params { threshold })
+    }
+}
+
+pub fn part_one(input: &str) -> Result<u64, SolveError> {
+    count_cheats(input, Params::default().threshold, 2)
+}
+
+pub fn part_two(input: &str) -> Result<u64, SolveError> {
+    count_cheats(input, Params::default().threshold, 20)
+}
+
+/// The racetrack with an arc for each two picosecond cheat that saves at least `n` picoseconds.
+/// The longer cheats of part two number in the millions, far too many to draw
+pub fn cheats_figure(file: &str, n: usize) -> Result<Figure, SolveError> {
+    let grid = expect_valid(parse_input(file));
+    let racetrack = find_racetrack(&grid)?;
+    let mut drawing = Drawing::new(grid.width(), grid.height());
+    drawing
+        .cells(grid.find_all(&'#'), Colour::GREY)
+        .route(&racetrack, Colour::BLUE)
+        .arcs(find_cheats(n, 2, &racetrack), Colour::RED)
+        .dot(racetrack[0], Colour::GREEN)
+        .dot(racetrack[racetrack.len() - 1], Colour::RED);
+    Ok(Figure {
+        name: "cheats",
+        drawing,
+    })
+}
+
+pub struct Solver;
+
+impl Solution for Solver {
+    type Params = Params;
+
+    fn parse(input: &str) -> Result<(), ParseError> {
+        black_box(parse_input(input))?;
+        Ok(())
+    }
+
+    fn part_one(input: &str, params: &Params) -> Solved {
+        Ok(count_cheats(input, params.threshold, 2)?.into())
+    }
+
+    fn part_two(input: &str, params: &Params) -> Solved {
+        Ok(count_cheats(input, params.threshold, 20)?.into())
+    }
+
+    fn figures(input: &str, params: &Params) -> Vec<Figure> {
+        cheats_figure(input, params.threshold)
+            .inspect_err(|error| warn!("{error}"))
+            .into_iter()
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE_DATA: &str = "###############
+#...#...#.....#
+#.#.#.#.#.###.#
+#S#...#.#.#...#
+#######.#.#.###
+#######.#.#...#
+#######.#.###.#
+###..E#...#...#
+###.#######.###
+#...###...#...#
+#.#####.#.###.#
+#.#...#.#.#...#
+#.#.#.#.#.#.###
+#...#...#...###
+###############";
+
+    #[test]
+    fn test_part_one_as_given() {
+        let result = count_cheats(&String::from(EXAMPLE_DATA), 20, 2);
+        assert_eq!(result, Ok(5));
+    }
+
+    #[test]
+    fn test_part_two_as_given() {
+        let result = count_cheats(&String::from(EXAMPLE_DATA), 76, 20);
+        assert_eq!(result, Ok(3));
+    }
+
+    #[test]
+    fn test_threshold_is_at_least_two() {
+        let mut settings = Settings::default();
+        settings.set("threshold", 1);
+        assert!(matches!(
+            Params::configure(&settings),
+            Err(ParamError::Invalid { .. })
+        ));
+        settings.set("threshold", 2);
+        assert_eq!(Params::configure(&settings), Ok(Params { threshold: 2 }));
+    }
+
+    #[test]
+    fn test_cheats_figure() {
+        let racetrack = find_racetrack(&parse_input(EXAMPLE_DATA).unwrap()).unwrap();
+        assert_eq!(find_cheats(64, 2, &racetrack).count(), 1);
+        let svg = cheats_figure(EXAMPLE_DATA, 64).unwrap().drawing.to_svg();
+        assert_eq!(svg.matches('Q').count(), 1);
+        assert_eq!(svg.matches("<polyline").count(), 1);
+    }
+
+    #[test]
+    fn test_missing_end_is_reported() {
+        let error = parse_input("#####\n#S..#\n#####\n").unwrap_err();
+        assert_eq!((error.line, error.column), (3, 6));
+        let error = parse_input("#####\n#S.S#\n#E###\n").unwrap_err();
+        assert_eq!(error.to_string(), "line 2, column 4: expected only one 'S'");
+    }
+
+    #[test]
+    fn test_dead_end_is_reported() {
+        let dead_end = "#####\n#S#E#\n#####\n";
+        assert_eq!(
+            count_cheats(dead_end, 2, 2),
+            Err(SolveError::NoAnswer(String::from(
+                "the track dead-ends at 1,1 before the end"
+            )))
+        );
+    }
+}
@@ -0,0 +1,219 @@
+//! Day 21: Keypad Conundrum
+
+use crate::{
+    answer::Solved,
+    debug,
+    geometry::{Direction, Point, Vector},
+    memo::Memo,
+    parse::{expect_valid, non_blank_lines, ParseError},
+    Solution,
+};
+use itertools::Itertools;
+use std::{collections::HashMap, hint::black_box};
+
+pub fn parse_input(file: &str) -> Result<Vec<String>, ParseError> {
+    non_blank_lines(file)
+        .map(|line| {
+            let code = line.trim().cells("a digit or A", |c| {
+                (c.is_ascii_digit() || c == 'A').then_some(c)
+            })?;
+            Ok(code.into_iter().collect())
+        })
+        .collect()
+}
+
+pub fn get_initial_movement_vector(required_movement_vector: Vector) -> Vec<Vec<char>> {
+    let horizontal = if required_movement_vector.x > 0 {
+        vec![Direction::Right.arrow(); required_movement_vector.x as usize]
+    } else {
+        vec![Direction::Left.arrow(); (-required_movement_vector.x) as usize]
+    };
+    let vertical = if required_movement_vector.y < 0 {
+        vec![Direction::Up.arrow(); (-required_movement_vector.y) as usize]
+    } else {
+        vec![Direction::Down.arrow(); (required_movement_vector.y) as usize]
+    };
+    vec![
+        [vertical.clone(), horizontal.clone()].concat(),
+        [horizontal, vertical].concat(),
+    ]
+}
+
+pub fn get_instruction_sequences(
+    keypad: &HashMap<char, Point>,
+    desired_output: &str,
+) -> Vec<String> {
+    // Returns a list of all combinations that generate `desired_output`
+    let mut sequence_fragments: Vec<Vec<String>> = Vec::new(); // A list of all permutations at
+                                                               // each step
+    let mut position = *keypad.get(&'A').unwrap();
+    for c in desired_output.chars() {
+        let destination = keypad.get(&c).unwrap();
+        let movements = get_initial_movement_vector(*destination - position);
+        let fragment = movements
+            .iter()
+            .filter(|&perm| {
+                let mut pos = position;
+                for &c in perm {
+                    pos += Direction::from_arrow(c)
+                        .expect("Unrecognised direction")
+                        .vector();
+                    if *keypad.get(&'X').unwrap() == pos {
+                        return false;
+                    }
+                }
+                true
+            })
+            .map(|perm| perm.iter().collect::<String>())
+            .collect_vec();
+        sequence_fragments.push(fragment);
+        position = *destination;
+    }
+
+    let mut possible_sequences: Vec<String> = vec![String::from("")];
+    for fragment in sequence_fragments {
+        let mut new_sequences = Vec::new();
+        for option in fragment.iter().unique() {
+            for sequence in &possible_sequences {
+                new_sequences.push(format!("{sequence}{option}A"));
+            }
+        }
+        possible_sequences = new_sequences;
+    }
+    possible_sequences
+}
+
+pub fn numeric_keypad() -> HashMap<char, Point> {
+    HashMap::from([
+        ('7', Point::new(0, 0)),
+        ('8', Point::new(1, 0)),
+        ('9', Point::new(2, 0)),
+        ('4', Point::new(0, 1)),
+        ('5', Point::new(1, 1)),
+        ('6', Point::new(2, 1)),
+        ('1', Point::new(0, 2)),
+        ('2', Point::new(1, 2)),
+        ('3', Point::new(2, 2)),
+        ('X', Point::new(0, 3)),
+        ('0', Point::new(1, 3)),
+        ('A', Point::new(2, 3)),
+    ])
+}
+
+pub fn robot_keypad() -> HashMap<char, Point> {
+    HashMap::from([
+        ('X', Point::new(0, 0)),
+        ('^', Point::new(1, 0)),
+        ('A', Point::new(2, 0)),
+        ('<', Point::new(0, 1)),
+        ('v', Point::new(1, 1)),
+        ('>', Point::new(2, 1)),
+    ])
+}
+
+/// The fewest presses needed to make `robots` directional keypad robots in a chain type
+/// `sequence`. Every press ends with the arm back on `A`, so each `...A` chunk of the sequence
+/// can be expanded separately, and the same few chunks come up again at every depth
+pub fn shortest_input_length(
+    memo: &mut Memo<(String, usize), u64>,
+    robot_keypad: &HashMap<char, Point>,
+    sequence: &str,
+    robots: usize,
+) -> u64 {
+    if robots == 0 {
+        return sequence.len() as u64;
+    }
+    memo.get_or_compute((sequence.to_string(), robots), |memo| {
+        sequence
+            .split_inclusive('A')
+            .map(|chunk| {
+                get_instruction_sequences(robot_keypad, chunk)
+                    .iter()
+                    .map(|option| shortest_input_length(memo, robot_keypad, option, robots - 1))
+                    .min()
+                    .expect("every chunk can be typed")
+            })
+            .sum()
+    })
+}
+
+/// The sum of each code's complexity when `robots` directional keypad robots stand between the
+/// person typing and the robot at the numeric keypad
+pub fn total_complexity(file: &str, robots: usize) -> u64 {
+    let codes = expect_valid(parse_input(file));
+    let keypad = numeric_keypad();
+    let robot_keypad = robot_keypad();
+    let mut memo = Memo::new();
+
+    let mut complexity = 0;
+    for code in codes {
+        let shortest_input_sequence_length = get_instruction_sequences(&keypad, &code)
+            .iter()
+            .map(|sequence| shortest_input_length(&mut memo, &robot_keypad, sequence, robots))
+            .min()
+            .expect("every code can be typed");
+        let numeric_sequence = code[..code.len() - 1].parse::<u64>().unwrap();
+
+        complexity += shortest_input_sequence_length * numeric_sequence;
+    }
+    debug!("input lengths memo: {}", memo.stats());
+    complexity
+}
+
+pub fn part_one(file: &str) -> u64 {
+    total_complexity(file, 2)
+}
+
+pub fn part_two(file: &str) -> u64 {
+    total_complexity(file, 25)
+}
+
+pub struct Solver;
+
+impl Solution for Solver {
+    type Params = ();
+
+    fn parse(input: &str) -> Result<(), ParseError> {
+        black_box(parse_input(input))?;
+        Ok(())
+    }
+
+    fn part_one(input: &str, _: &()) -> Solved {
+        Ok(part_one(input).into())
+    }
+
+    fn part_two(input: &str, _: &()) -> Solved {
+        Ok(part_two(input).into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE_DATA: &str = "029A
+980A
+179A
+456A
+379A";
+
+    #[test]
+    fn test_part_one_as_given() {
+        let result = part_one(&String::from(EXAMPLE_DATA));
+        assert_eq!(result, 126384);
+    }
+
+    #[test]
+    fn test_part_two_as_given() {
+        let result = part_two(&String::from(EXAMPLE_DATA));
+        assert_eq!(result, 154115708116294);
+    }
+
+    #[test]
+    fn test_single_press_lengths() {
+        let mut memo = Memo::new();
+        let robot_keypad = robot_keypad();
+        assert_eq!(shortest_input_length(&mut memo, &robot_keypad, "A", 25), 1);
+        assert_eq!(shortest_input_length(&mut memo, &robot_keypad, "<A", 1), 8);
+    }
+}
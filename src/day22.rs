@@ -0,0 +1,125 @@
+//! Day 22: Monkey Market
+
+use crate::{
+    answer::Solved,
+    parse::{expect_valid, non_blank_lines, ParseError},
+    Solution,
+};
+use std::{
+    collections::{HashMap, HashSet, VecDeque},
+    hint::black_box,
+};
+
+pub fn parse_input(file: &str) -> Result<Vec<u64>, ParseError> {
+    non_blank_lines(file).map(|line| line.integer()).collect()
+}
+
+pub const MOD: u64 = 16777216u64;
+
+pub fn next_secret(secret: u64) -> u64 {
+    let first = ((secret << 6) ^ secret) % MOD;
+    let second = ((first >> 5) ^ first) % MOD;
+    ((second << 11) ^ second) % MOD
+}
+
+pub fn generate_nth_secret(secret: u64, n: u64) -> u64 {
+    let mut s = secret;
+    for _i in 0..n {
+        s = next_secret(s);
+    }
+    s
+}
+
+pub fn part_one(file: &str) -> u64 {
+    let secret_numbers = expect_valid(parse_input(file));
+    secret_numbers
+        .iter()
+        .map(|&d| generate_nth_secret(d, 2000))
+        .sum()
+}
+
+pub fn generate_deltas(secret: u64, n: u64) -> Vec<(u64, i64)> {
+    let mut previous = secret;
+    let mut v = Vec::new();
+    for _i in 0..n {
+        let s = next_secret(previous);
+        let price = s % 10;
+        let previous_price = (previous % 10) as i64;
+        v.push((price % 10, price as i64 - previous_price));
+        previous = s;
+    }
+    v
+}
+pub fn part_two(file: &str) -> u64 {
+    let secret_numbers = expect_valid(parse_input(file));
+    let mut sequence_earnings = HashMap::new();
+    for secret in secret_numbers {
+        let deltas = generate_deltas(secret, 2000);
+        let mut queue = VecDeque::new();
+        let mut seen = HashSet::new();
+        for (price, delta) in deltas {
+            queue.push_back(delta);
+            if queue.len() < 4 {
+                continue;
+            }
+            let key = queue[0] * 8000 + queue[1] * 400 + queue[2] * 20 + queue[3];
+            if seen.contains(&key) {
+                queue.pop_front();
+                continue;
+            }
+            let e: &mut u64 = sequence_earnings.entry(key).or_default();
+            *e += price;
+            seen.insert(key);
+            queue.pop_front();
+        }
+    }
+    sequence_earnings
+        .iter()
+        .fold(0, |acc, (_key, &total)| std::cmp::max(acc, total))
+}
+
+pub struct Solver;
+
+impl Solution for Solver {
+    type Params = ();
+
+    fn parse(input: &str) -> Result<(), ParseError> {
+        black_box(parse_input(input))?;
+        Ok(())
+    }
+
+    fn part_one(input: &str, _: &()) -> Solved {
+        Ok(part_one(input).into())
+    }
+
+    fn part_two(input: &str, _: &()) -> Solved {
+        Ok(part_two(input).into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE_DATA: &str = "1
+10
+100
+2024";
+
+    const SECOND_EXAMPLE: &str = "1
+2
+3
+2024";
+
+    #[test]
+    fn test_part_one_as_given() {
+        let result = part_one(&String::from(EXAMPLE_DATA));
+        assert_eq!(result, 37327623);
+    }
+
+    #[test]
+    fn test_part_two_as_given() {
+        let result = part_two(&String::from(SECOND_EXAMPLE));
+        assert_eq!(result, 23);
+    }
+}
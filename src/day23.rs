@@ -0,0 +1,140 @@
+//! Day 23: LAN Party
+
+use crate::{
+    answer::Solved,
+    parse::{expect_valid, non_blank_lines, ParseError},
+    trace, Solution,
+};
+use itertools::Itertools;
+use std::{
+    collections::{HashMap, HashSet},
+    hint::black_box,
+};
+
+pub fn parse_input(file: &str) -> Result<HashMap<&str, HashSet<&str>>, ParseError> {
+    let mut map = HashMap::new();
+    for line in non_blank_lines(file) {
+        let (left, right) = line.key_value("-")?;
+        let l = map.entry(left.text).or_insert(HashSet::new());
+        l.insert(right.text);
+        let r = map.entry(right.text).or_insert(HashSet::new());
+        r.insert(left.text);
+    }
+    Ok(map)
+}
+
+pub fn part_one(file: &str) -> u64 {
+    let connection_map = expect_valid(parse_input(file));
+    let mut combinations = HashSet::new();
+    for (source, destinations) in &connection_map {
+        if !source.starts_with('t') {
+            continue;
+        }
+        for destination in destinations {
+            for possible_mutual in connection_map.get(destination).unwrap() {
+                if destinations.contains(possible_mutual) {
+                    let mut v = [possible_mutual, destination, source];
+                    v.sort();
+                    combinations.insert(v.iter().join(","));
+                }
+            }
+        }
+    }
+    combinations.len() as u64
+}
+
+pub fn part_two(file: &str) -> String {
+    let connection_map = expect_valid(parse_input(file));
+    let mut parties: Vec<HashSet<&str>> = Vec::new();
+    for (source, destinations) in &connection_map {
+        'find_membership: for party in parties.iter_mut() {
+            for computer in party.iter() {
+                if !destinations.contains(computer) {
+                    continue 'find_membership;
+                }
+            }
+            party.insert(source);
+        }
+        for destination in destinations {
+            parties.push(HashSet::from([*source, *destination]));
+        }
+    }
+    trace!("parties: {parties:?}");
+    let (_longest_length, longest_party) = parties.iter().fold((0, HashSet::new()), |acc, el| {
+        if el.len() > acc.0 {
+            (el.len(), el.clone())
+        } else {
+            acc
+        }
+    });
+    longest_party.iter().sorted().join(",")
+}
+
+pub struct Solver;
+
+impl Solution for Solver {
+    type Params = ();
+
+    fn parse(input: &str) -> Result<(), ParseError> {
+        black_box(parse_input(input))?;
+        Ok(())
+    }
+
+    fn part_one(input: &str, _: &()) -> Solved {
+        Ok(part_one(input).into())
+    }
+
+    fn part_two(input: &str, _: &()) -> Solved {
+        Ok(part_two(input).into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE_DATA: &str = "kh-tc
+qp-kh
+de-cg
+ka-co
+yn-aq
+qp-ub
+cg-tb
+vc-aq
+tb-ka
+wh-tc
+yn-cg
+kh-ub
+ta-co
+de-co
+tc-td
+tb-wq
+wh-td
+ta-ka
+td-qp
+aq-cg
+wq-ub
+ub-vc
+de-ta
+wq-aq
+wq-vc
+wh-yn
+ka-de
+kh-ta
+co-tc
+wh-qp
+tb-vc
+td-yn";
+
+    #[test]
+    fn test_part_one_as_given() {
+        let result = part_one(&String::from(EXAMPLE_DATA));
+        assert_eq!(result, 7);
+    }
+
+    #[test]
+    fn test_part_two_as_given() {
+        let result = part_two(&String::from(EXAMPLE_DATA));
+        assert_eq!(result, "co,de,ka,ta");
+    }
+}
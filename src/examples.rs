@@ -0,0 +1,361 @@
+use std::{
+    fmt, fs, io,
+    path::{Path, PathBuf},
+};
+
+use regex::Regex;
+
+use crate::{
+    answer::Answer,
+    params::{Settings, PARAMETERS},
+    Part,
+};
+
+/// Directory inside the data directory holding one example fixture file per day
+pub const EXAMPLES_DIR: &str = "examples";
+
+/// Comment opening a fixture that was corrected by hand, which the extractor then never overwrites.
+/// It is followed by what extraction gets wrong, as in `# hand-maintained: needs width=11`
+pub const HAND_MAINTAINED: &str = "# hand-maintained";
+
+/// A worked example from a puzzle description, with the answer the description gives for it
+#[derive(Clone, Debug, PartialEq)]
+pub struct Example {
+    pub part: Part,
+    pub answer: Answer,
+    pub input: String,
+    /// Parameters the example needs, such as the smaller grid used by the example
+    pub settings: Settings,
+    /// Why the example can't be run against the solver as it stands, e.g. because the example
+    /// uses a smaller grid than the real puzzle
+    pub skip: Option<String>,
+}
+
+#[derive(Debug)]
+pub enum FixtureError {
+    Unreadable(PathBuf, io::Error),
+    Malformed { line: usize, text: String },
+}
+
+impl fmt::Display for FixtureError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            FixtureError::Unreadable(path, error) => {
+                write!(f, "Failed to read {}: {error}", path.display())
+            }
+            FixtureError::Malformed { line, text } => {
+                write!(f, "Malformed example header on line {line}: {text}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for FixtureError {}
+
+pub fn description_path(dir: &Path, day: u8) -> PathBuf {
+    dir.join(format!("{day:02}_description.txt"))
+}
+
+pub fn fixture_path(dir: &Path, day: u8) -> PathBuf {
+    dir.join(EXAMPLES_DIR).join(format!("{day:02}.txt"))
+}
+
+/// Splits text into paragraphs, keeping the lines of each
+fn paragraphs(text: &str) -> Vec<Vec<&str>> {
+    let mut paragraphs = vec![Vec::new()];
+    for line in text.lines() {
+        if line.trim().is_empty() {
+            if !paragraphs.last().unwrap().is_empty() {
+                paragraphs.push(Vec::new());
+            }
+        } else {
+            paragraphs.last_mut().unwrap().push(line);
+        }
+    }
+    paragraphs.retain(|paragraph| !paragraph.is_empty());
+    paragraphs
+}
+
+/// Whether a line reads like a sentence rather than puzzle input. Inputs such as
+/// `Button A: X+94, Y+34` contain a word or two, but never several lowercase words in a row
+fn is_prose(line: &str) -> bool {
+    let words = line
+        .split_whitespace()
+        .map(|word| word.trim_end_matches(|c: char| ",.;:!?".contains(c)))
+        .filter(|word| {
+            word.len() >= 3
+                && word.chars().all(|c| c.is_alphabetic() || c == '\'')
+                && word.chars().any(|c| c.is_lowercase())
+        })
+        .count();
+    words >= 4
+}
+
+/// Whether a paragraph looks like puzzle input. Indented paragraphs are bullet lists
+fn is_data(paragraph: &[&str]) -> bool {
+    paragraph
+        .iter()
+        .all(|line| !line.starts_with(char::is_whitespace) && !is_prose(line))
+}
+
+/// Whether a paragraph introduces an example input, as in `... For example:`
+fn introduces_example(paragraph: &[&str]) -> bool {
+    let last = paragraph.last().unwrap().trim_end();
+    last.ends_with(':') && paragraph.join(" ").to_lowercase().contains("example")
+}
+
+/// Finds the example input that the answer at `end` is about: the first one introduced, unless a
+/// larger example follows it. Inputs made of several sections, like rules followed by updates,
+/// are the run of data paragraphs that follows the introduction
+fn find_example_input(paragraphs: &[Vec<&str>], end: usize) -> Option<String> {
+    let introductions = paragraphs[..end]
+        .windows(2)
+        .enumerate()
+        .filter(|(_, pair)| introduces_example(&pair[0]) && is_data(&pair[1]))
+        .map(|(i, pair)| (i + 1, pair[0].join(" ").contains("larger example")))
+        .collect::<Vec<(usize, bool)>>();
+    let start = introductions
+        .iter()
+        .find(|(_, larger)| *larger)
+        .or(introductions.first())?
+        .0;
+    let sections = paragraphs[start..end]
+        .iter()
+        .take_while(|paragraph| is_data(paragraph))
+        .map(|paragraph| paragraph.join("\n") + "\n")
+        .collect::<Vec<String>>();
+    Some(sections.join("\n"))
+}
+
+/// Finds the answer to the example: the last number or comma-separated list in the last prose
+/// paragraph with one, before the closing question. Asides in brackets are ignored, so that
+/// `31 (9 + 4 + 0 + 0 + 9 + 9)` gives 31. Returns the index of that paragraph with the answer
+fn find_example_answer(paragraphs: &[Vec<&str>]) -> Option<(usize, String)> {
+    let aside = Regex::new(r"\([^)]*\)").unwrap();
+    let candidate = Regex::new(r"\b(\d+(,\d+)*|[a-z]{2}(,[a-z]{2})+)\b").unwrap();
+    let (_, body) = paragraphs.split_last()?;
+    body.iter()
+        .enumerate()
+        .rev()
+        .filter(|(_, paragraph)| {
+            !is_data(paragraph) && !paragraph[0].starts_with(char::is_whitespace)
+        })
+        .find_map(|(i, paragraph)| {
+            let text = aside.replace_all(&paragraph.join(" "), "").to_string();
+            let answer = candidate.find_iter(&text).last()?;
+            Some((i, answer.as_str().to_string()))
+        })
+}
+
+/// Pulls the example input and expected answer for each part out of a puzzle description. The
+/// second part reuses the first part's example unless it introduces one of its own
+pub fn extract(description: &str) -> Vec<Example> {
+    let mut examples = Vec::new();
+    let mut previous_input = None;
+    let sections = description.split("--- Part Two ---");
+    for (part, section) in [Part::One, Part::Two].into_iter().zip(sections) {
+        let paragraphs = paragraphs(section);
+        let Some((end, answer)) = find_example_answer(&paragraphs) else {
+            continue;
+        };
+        let input = find_example_input(&paragraphs, end).or(previous_input.clone());
+        if let Some(input) = &input {
+            let Ok(answer) = answer.parse();
+            examples.push(Example {
+                part,
+                answer,
+                input: input.clone(),
+                settings: Settings::default(),
+                skip: None,
+            });
+        }
+        previous_input = input;
+    }
+    examples
+}
+
+/// Renders examples in the fixture format: each example starts with a header line
+/// `=== part <n>: <answer>`, optionally followed by ` with <name>=<value> ...` for the parameters it
+/// needs and then by ` (skipped: <reason>)`, and its input follows
+pub fn to_fixture(examples: &[Example]) -> String {
+    let mut fixture = String::new();
+    for example in examples {
+        fixture += &format!("=== part {}: {}", example.part, example.answer);
+        if !example.settings.is_empty() {
+            fixture += " with";
+            for (name, value) in example.settings.iter() {
+                fixture += &format!(" {name}={value}");
+            }
+        }
+        if let Some(reason) = &example.skip {
+            fixture += &format!(" (skipped: {reason})");
+        }
+        fixture += "\n";
+        fixture += &example.input;
+    }
+    fixture
+}
+
+/// Whether a fixture is marked as corrected by hand, in one of the comments before its examples
+pub fn is_hand_maintained(fixture: &str) -> bool {
+    fixture
+        .lines()
+        .take_while(|line| !line.starts_with("==="))
+        .any(|line| line.starts_with(HAND_MAINTAINED))
+}
+
+pub fn parse_fixture(text: &str) -> Result<Vec<Example>, FixtureError> {
+    let header =
+        Regex::new(r"^=== part ([12]): (\S+)(?: with ((?: ?\w+=\d+)+))?(?: \(skipped: (.*)\))?$")
+            .unwrap();
+    let mut examples: Vec<Example> = Vec::new();
+    for (i, line) in text.lines().enumerate() {
+        if !line.starts_with("===") {
+            match examples.last_mut() {
+                Some(example) => {
+                    example.input += line;
+                    example.input += "\n";
+                }
+                None if line.starts_with('#') || line.trim().is_empty() => (),
+                None => {
+                    return Err(FixtureError::Malformed {
+                        line: i + 1,
+                        text: line.to_string(),
+                    })
+                }
+            }
+            continue;
+        }
+        let malformed = || FixtureError::Malformed {
+            line: i + 1,
+            text: line.to_string(),
+        };
+        let captures = header.captures(line).ok_or_else(malformed)?;
+        let Ok(answer) = captures[2].parse();
+        let mut settings = Settings::default();
+        for setting in captures
+            .get(3)
+            .map_or("", |m| m.as_str())
+            .split_whitespace()
+        {
+            let (name, value) = setting.split_once('=').ok_or_else(malformed)?;
+            if !PARAMETERS.contains(&name) {
+                return Err(malformed());
+            }
+            settings.set(name, value.parse().map_err(|_| malformed())?);
+        }
+        examples.push(Example {
+            part: if &captures[1] == "1" {
+                Part::One
+            } else {
+                Part::Two
+            },
+            answer,
+            input: String::new(),
+            settings,
+            skip: captures.get(4).map(|reason| reason.as_str().to_string()),
+        });
+    }
+    Ok(examples)
+}
+
+/// Reads the fixture for a day. A day without a fixture file has no examples
+pub fn load_fixture(dir: &Path, day: u8) -> Result<Vec<Example>, FixtureError> {
+    let path = fixture_path(dir, day);
+    match fs::read_to_string(&path) {
+        Ok(text) => parse_fixture(&text),
+        Err(error) if error.kind() == io::ErrorKind::NotFound => Ok(Vec::new()),
+        Err(error) => Err(FixtureError::Unreadable(path, error)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DESCRIPTION: &str = "--- Day 1: Historian Hysteria ---
+
+There's just one problem. Maybe you can help The Historians reconcile their lists?
+
+For example:
+
+3   4
+4   3
+
+To find the total distance between the left list and the right list, add up the distances between all of the pairs you found:
+
+    The smallest number in the left list is 3, and the smallest number in the right list is 3.
+
+In the example above, this is 0 + 1, a total distance of 1!
+
+What is the total distance between your lists?
+
+--- Part Two ---
+
+Here are the same example lists again:
+
+3   4
+4   3
+
+So, for these example lists, the similarity score is 15 (3 + 12).
+
+What is their similarity score?
+";
+
+    #[test]
+    fn test_extract() {
+        let examples = extract(DESCRIPTION);
+        assert_eq!(examples.len(), 2);
+        assert_eq!(examples[0].part, Part::One);
+        assert_eq!(examples[0].answer, Answer::Number(1));
+        assert_eq!(examples[0].input, "3   4\n4   3\n");
+        assert_eq!(examples[1].part, Part::Two);
+        assert_eq!(examples[1].answer, Answer::Number(15));
+        assert_eq!(examples[1].input, "3   4\n4   3\n");
+    }
+
+    #[test]
+    fn test_prose_and_data() {
+        assert!(is_prose("This example data contains six reports."));
+        assert!(!is_prose("Button A: X+94, Y+34"));
+        assert!(!is_prose("x00 AND y00 -> z00"));
+        assert!(!is_prose("r, wr, b, g, bwu, rb, gb, br"));
+    }
+
+    #[test]
+    fn test_fixture_round_trip() {
+        let mut settings = Settings::default();
+        settings.set("width", 7);
+        settings.set("bytes", 12);
+        let examples = vec![
+            Example {
+                part: Part::One,
+                answer: Answer::from("4,6,3"),
+                input: String::from("Register A: 729\n\nProgram: 0,1,5\n"),
+                settings: Settings::default(),
+                skip: None,
+            },
+            Example {
+                part: Part::Two,
+                answer: Answer::Number(22),
+                input: String::from("5,4\n4,2\n"),
+                settings,
+                skip: Some(String::from("the example is too slow")),
+            },
+        ];
+        let fixture = to_fixture(&examples);
+        assert_eq!(parse_fixture(&fixture).unwrap(), examples);
+        assert!(fixture.contains("=== part 2: 22 with bytes=12 width=7 (skipped: "));
+        assert!(parse_fixture("=== part 3: 1\n").is_err());
+        assert!(parse_fixture("=== part 1: 1 with colour=3\n").is_err());
+        assert!(parse_fixture("stray input\n").is_err());
+    }
+
+    #[test]
+    fn test_hand_maintained() {
+        let fixture = "# hand-maintained: needs width=11\n=== part 1: 12\n#.#\n";
+        assert!(is_hand_maintained(fixture));
+        assert_eq!(parse_fixture(fixture).unwrap()[0].input, "#.#\n");
+        assert!(!is_hand_maintained("=== part 1: 12\n# hand-maintained\n"));
+    }
+}
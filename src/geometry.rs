@@ -0,0 +1,246 @@
+use std::{
+    fmt,
+    ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign},
+};
+
+use crate::grid::Position;
+
+/// A location on a map as `(x, y)`, with `y` growing downwards. Unlike a grid `Position` it can
+/// be negative, so it is safe to step off the edge of a map and check afterwards
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Point {
+    pub x: isize,
+    pub y: isize,
+}
+
+/// The difference between two points
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Vector {
+    pub x: isize,
+    pub y: isize,
+}
+
+/// One of the four orthogonal directions on a map, with up being towards the first row
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Direction {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+impl Point {
+    pub const fn new(x: isize, y: isize) -> Point {
+        Point { x, y }
+    }
+
+    pub fn manhattan(self, other: Point) -> usize {
+        (other - self).manhattan()
+    }
+
+    /// The grid position of this point, if neither coordinate is negative
+    pub fn position(self) -> Option<Position> {
+        Some((usize::try_from(self.x).ok()?, usize::try_from(self.y).ok()?))
+    }
+}
+
+impl From<Position> for Point {
+    fn from((x, y): Position) -> Point {
+        Point::new(x as isize, y as isize)
+    }
+}
+
+impl Vector {
+    pub const fn new(x: isize, y: isize) -> Vector {
+        Vector { x, y }
+    }
+
+    pub fn manhattan(self) -> usize {
+        self.x.unsigned_abs() + self.y.unsigned_abs()
+    }
+}
+
+impl From<Vector> for (isize, isize) {
+    fn from(vector: Vector) -> (isize, isize) {
+        (vector.x, vector.y)
+    }
+}
+
+impl Add<Vector> for Point {
+    type Output = Point;
+
+    fn add(self, vector: Vector) -> Point {
+        Point::new(self.x + vector.x, self.y + vector.y)
+    }
+}
+
+impl AddAssign<Vector> for Point {
+    fn add_assign(&mut self, vector: Vector) {
+        *self = *self + vector;
+    }
+}
+
+impl Sub<Vector> for Point {
+    type Output = Point;
+
+    fn sub(self, vector: Vector) -> Point {
+        self + -vector
+    }
+}
+
+impl SubAssign<Vector> for Point {
+    fn sub_assign(&mut self, vector: Vector) {
+        *self = *self - vector;
+    }
+}
+
+impl Sub for Point {
+    type Output = Vector;
+
+    fn sub(self, other: Point) -> Vector {
+        Vector::new(self.x - other.x, self.y - other.y)
+    }
+}
+
+impl Add for Vector {
+    type Output = Vector;
+
+    fn add(self, other: Vector) -> Vector {
+        Vector::new(self.x + other.x, self.y + other.y)
+    }
+}
+
+impl Neg for Vector {
+    type Output = Vector;
+
+    fn neg(self) -> Vector {
+        Vector::new(-self.x, -self.y)
+    }
+}
+
+impl Mul<isize> for Vector {
+    type Output = Vector;
+
+    fn mul(self, scale: isize) -> Vector {
+        Vector::new(self.x * scale, self.y * scale)
+    }
+}
+
+impl Direction {
+    /// Every direction, clockwise from up
+    pub const ALL: [Direction; 4] = [
+        Direction::Up,
+        Direction::Right,
+        Direction::Down,
+        Direction::Left,
+    ];
+
+    /// Parses one of the arrows `^`, `>`, `v` or `<`
+    pub fn from_arrow(arrow: char) -> Option<Direction> {
+        match arrow {
+            '^' => Some(Direction::Up),
+            '>' => Some(Direction::Right),
+            'v' => Some(Direction::Down),
+            '<' => Some(Direction::Left),
+            _ => None,
+        }
+    }
+
+    pub fn arrow(self) -> char {
+        match self {
+            Direction::Up => '^',
+            Direction::Right => '>',
+            Direction::Down => 'v',
+            Direction::Left => '<',
+        }
+    }
+
+    /// The unit vector one step in this direction
+    pub fn vector(self) -> Vector {
+        match self {
+            Direction::Up => Vector::new(0, -1),
+            Direction::Right => Vector::new(1, 0),
+            Direction::Down => Vector::new(0, 1),
+            Direction::Left => Vector::new(-1, 0),
+        }
+    }
+
+    pub fn clockwise(self) -> Direction {
+        Direction::ALL[(self as usize + 1) % 4]
+    }
+
+    pub fn counter_clockwise(self) -> Direction {
+        Direction::ALL[(self as usize + 3) % 4]
+    }
+
+    pub fn opposite(self) -> Direction {
+        Direction::ALL[(self as usize + 2) % 4]
+    }
+
+    pub fn is_horizontal(self) -> bool {
+        matches!(self, Direction::Left | Direction::Right)
+    }
+
+    /// The fewest quarter turns needed to face `other`, from 0 to 2
+    pub fn turns_to(self, other: Direction) -> usize {
+        let turns = (other as usize + 4 - self as usize) % 4;
+        turns.min(4 - turns)
+    }
+}
+
+impl fmt::Display for Direction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.arrow())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_point_arithmetic() {
+        let start = Point::new(2, 3);
+        let end = start + Direction::Left.vector() * 4;
+        assert_eq!(end, Point::new(-2, 3));
+        assert_eq!(end - start, Vector::new(-4, 0));
+        assert_eq!(start.manhattan(Point::new(5, -1)), 7);
+        assert_eq!(end.position(), None);
+        assert_eq!(start.position(), Some((2, 3)));
+        assert_eq!(Point::from((7, 1)), Point::new(7, 1));
+    }
+
+    #[test]
+    fn test_turning() {
+        assert_eq!(Direction::Up.clockwise(), Direction::Right);
+        assert_eq!(Direction::Up.counter_clockwise(), Direction::Left);
+        assert_eq!(Direction::Left.clockwise(), Direction::Up);
+        assert_eq!(Direction::Right.opposite(), Direction::Left);
+        assert_eq!(Direction::Up.turns_to(Direction::Up), 0);
+        assert_eq!(Direction::Left.turns_to(Direction::Up), 1);
+        assert_eq!(Direction::Up.turns_to(Direction::Left), 1);
+        assert_eq!(Direction::Down.turns_to(Direction::Up), 2);
+        for direction in Direction::ALL {
+            assert_eq!(
+                direction.vector() + direction.opposite().vector(),
+                Vector::default()
+            );
+        }
+    }
+
+    #[test]
+    fn test_arrows() {
+        let arrows = "^>v<";
+        let directions = arrows
+            .chars()
+            .map(Direction::from_arrow)
+            .collect::<Option<Vec<_>>>()
+            .unwrap();
+        assert_eq!(directions, Direction::ALL);
+        assert_eq!(
+            directions.iter().map(|d| d.to_string()).collect::<String>(),
+            arrows
+        );
+        assert_eq!(Direction::from_arrow('x'), None);
+    }
+}
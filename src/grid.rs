@@ -0,0 +1,304 @@
+use std::{
+    fmt,
+    ops::{Index, IndexMut},
+};
+
+use crate::geometry::Direction;
+
+/// A cell in a grid as `(x, y)`, with `x` counting columns from the left and `y` counting rows
+/// from the top
+pub type Position = (usize, usize);
+
+/// Offsets to the four orthogonal neighbours, clockwise from up
+pub const ORTHOGONAL: [(isize, isize); 4] = [(0, -1), (1, 0), (0, 1), (-1, 0)];
+
+/// Offsets to all eight neighbours, clockwise from up
+pub const SURROUNDING: [(isize, isize); 8] = [
+    (0, -1),
+    (1, -1),
+    (1, 0),
+    (1, 1),
+    (0, 1),
+    (-1, 1),
+    (-1, 0),
+    (-1, -1),
+];
+
+/// A rectangular map of cells, stored row by row
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    pub fn new(width: usize, height: usize, fill: T) -> Grid<T>
+    where
+        T: Clone,
+    {
+        Grid {
+            width,
+            height,
+            cells: vec![fill; width * height],
+        }
+    }
+
+    /// Builds a grid from its rows, which must all be the same, non-zero length. Input is checked
+    /// by `parse::grid`, which points at a ragged row rather than panicking
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Grid<T> {
+        let width = rows.first().map_or(0, Vec::len);
+        assert!(width > 0, "a grid needs at least one cell");
+        let height = rows.len();
+        let mut cells = Vec::with_capacity(width * height);
+        for (y, row) in rows.into_iter().enumerate() {
+            assert_eq!(
+                row.len(),
+                width,
+                "row {y} is a different length from the first"
+            );
+            cells.extend(row);
+        }
+        Grid {
+            width,
+            height,
+            cells,
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, (x, y): Position) -> bool {
+        x < self.width && y < self.height
+    }
+
+    pub fn get(&self, position: Position) -> Option<&T> {
+        if self.contains(position) {
+            Some(&self.cells[position.1 * self.width + position.0])
+        } else {
+            None
+        }
+    }
+
+    pub fn get_mut(&mut self, position: Position) -> Option<&mut T> {
+        if self.contains(position) {
+            Some(&mut self.cells[position.1 * self.width + position.0])
+        } else {
+            None
+        }
+    }
+
+    /// The index of a position among all the cells, counting row by row, for flat per-cell tables
+    pub fn cell_index(&self, (x, y): Position) -> usize {
+        y * self.width + x
+    }
+
+    /// The position of the cell at `index`, counting row by row
+    pub fn cell_position(&self, index: usize) -> Position {
+        (index % self.width, index / self.width)
+    }
+
+    /// Converts signed coordinates to a position, if they fall inside the grid
+    pub fn position(&self, x: isize, y: isize) -> Option<Position> {
+        let position = (usize::try_from(x).ok()?, usize::try_from(y).ok()?);
+        self.contains(position).then_some(position)
+    }
+
+    /// The position `offset` away from `position`, if it is inside the grid
+    pub fn offset(&self, (x, y): Position, (dx, dy): (isize, isize)) -> Option<Position> {
+        let position = (x.checked_add_signed(dx)?, y.checked_add_signed(dy)?);
+        self.contains(position).then_some(position)
+    }
+
+    /// The position one step from `position` in `direction`, if it is inside the grid
+    pub fn step(&self, position: Position, direction: Direction) -> Option<Position> {
+        self.offset(position, direction.vector().into())
+    }
+
+    /// Orthogonal neighbours of a position that are inside the grid
+    pub fn neighbours(&self, position: Position) -> impl Iterator<Item = Position> + '_ {
+        ORTHOGONAL
+            .iter()
+            .filter_map(move |&offset| self.offset(position, offset))
+    }
+
+    /// Orthogonal and diagonal neighbours of a position that are inside the grid
+    pub fn surrounding(&self, position: Position) -> impl Iterator<Item = Position> + '_ {
+        SURROUNDING
+            .iter()
+            .filter_map(move |&offset| self.offset(position, offset))
+    }
+
+    /// Every position in the grid, row by row
+    pub fn positions(&self) -> impl Iterator<Item = Position> {
+        let width = self.width;
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| (x, y)))
+    }
+
+    /// Every position with its cell, row by row
+    pub fn iter(&self) -> impl Iterator<Item = (Position, &T)> {
+        self.positions().zip(self.cells.iter())
+    }
+
+    /// The first position, row by row, holding `value`
+    pub fn find(&self, value: &T) -> Option<Position>
+    where
+        T: PartialEq,
+    {
+        self.iter()
+            .find(|(_, cell)| *cell == value)
+            .map(|(position, _)| position)
+    }
+
+    /// Every position holding `value`, row by row
+    pub fn find_all<'a>(&'a self, value: &'a T) -> impl Iterator<Item = Position> + 'a
+    where
+        T: PartialEq,
+    {
+        self.iter()
+            .filter(move |(_, cell)| *cell == value)
+            .map(|(position, _)| position)
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.cells.chunks(self.width)
+    }
+
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        self.cells.iter().skip(x).step_by(self.width)
+    }
+
+    pub fn map<U, F: FnMut(&T) -> U>(&self, f: F) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+}
+
+impl<T> Index<Position> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, position: Position) -> &T {
+        self.get(position)
+            .unwrap_or_else(|| panic!("{position:?} is outside the grid"))
+    }
+}
+
+impl<T> IndexMut<Position> for Grid<T> {
+    fn index_mut(&mut self, position: Position) -> &mut T {
+        self.get_mut(position)
+            .unwrap_or_else(|| panic!("{position:?} is outside the grid"))
+    }
+}
+
+/// Writes the grid row by row, each cell as it displays
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for row in self.rows() {
+            for cell in row {
+                write!(f, "{cell}")?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse::{char_grid, non_blank_lines};
+
+    const MAP: &str = "
+        #S.
+        .#E
+    ";
+
+    fn parse(text: &str) -> Grid<char> {
+        char_grid(non_blank_lines(text)).unwrap()
+    }
+
+    #[test]
+    fn test_from_rows_and_display() {
+        let grid = parse(MAP);
+        assert_eq!(grid.width(), 3);
+        assert_eq!(grid.height(), 2);
+        assert_eq!(grid[(2, 1)], 'E');
+        assert_eq!(grid.to_string(), "#S.\n.#E\n");
+        assert_eq!(
+            Grid::from_rows(vec![vec![1, 2], vec![3, 4]]).row(1),
+            &[3, 4]
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "row 1 is a different length from the first")]
+    fn test_ragged_rows() {
+        Grid::from_rows(vec![vec!['a', 'b'], vec!['c']]);
+    }
+
+    #[test]
+    fn test_find_and_views() {
+        let grid = parse(MAP);
+        assert_eq!(grid.find(&'S'), Some((1, 0)));
+        assert_eq!(grid.find(&'^'), None);
+        assert_eq!(
+            grid.find_all(&'#').collect::<Vec<_>>(),
+            vec![(0, 0), (1, 1)]
+        );
+        assert_eq!(grid.row(1), &['.', '#', 'E']);
+        assert_eq!(grid.column(2).collect::<String>(), ".E");
+        assert_eq!(grid.rows().count(), 2);
+        assert_eq!(
+            grid.map(|&c| c == '#')
+                .iter()
+                .filter(|(_, &wall)| wall)
+                .count(),
+            2
+        );
+    }
+
+    #[test]
+    fn test_neighbours() {
+        let grid = Grid::new(3, 3, 0);
+        assert_eq!(
+            grid.neighbours((0, 0)).collect::<Vec<_>>(),
+            vec![(1, 0), (0, 1)]
+        );
+        assert_eq!(grid.neighbours((1, 1)).count(), 4);
+        assert_eq!(grid.surrounding((1, 1)).count(), 8);
+        assert_eq!(
+            grid.surrounding((2, 0)).collect::<Vec<_>>(),
+            vec![(2, 1), (1, 1), (1, 0)]
+        );
+        assert_eq!(grid.offset((2, 2), (1, 0)), None);
+        assert_eq!(grid.step((2, 2), Direction::Up), Some((2, 1)));
+        assert_eq!(grid.step((0, 2), Direction::Left), None);
+        assert_eq!(grid.position(-1, 0), None);
+        assert_eq!(grid.position(2, 1), Some((2, 1)));
+    }
+
+    #[test]
+    fn test_indexing() {
+        let mut grid = Grid::new(2, 2, false);
+        grid[(1, 0)] = true;
+        assert_eq!(grid.get((1, 0)), Some(&true));
+        assert_eq!(grid.get((2, 0)), None);
+        assert_eq!(grid.cell_index((1, 1)), 3);
+        assert_eq!(grid.cell_position(2), (0, 1));
+        *grid.get_mut((0, 1)).unwrap() = true;
+        assert_eq!(grid.to_string(), "falsetrue\ntruefalse\n");
+    }
+}
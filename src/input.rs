@@ -0,0 +1,258 @@
+use std::{
+    env, fmt, fs,
+    io::{self, Read},
+    path::{Path, PathBuf},
+};
+
+/// Environment variable that overrides where the puzzle data files live
+pub const DATA_DIR_VARIABLE: &str = "AOC_DATA_DIR";
+
+/// Name given to the checked-in `NN_data.txt` input when listing input sets
+pub const DEFAULT_SET: &str = "default";
+
+/// Where to read a day's puzzle input from
+#[derive(Clone, Debug, PartialEq)]
+pub enum InputSource {
+    /// The checked-in `NN_data.txt` file in the given data directory
+    DataFile(PathBuf),
+    /// An alternate input set `NN_data.<name>.txt` in the given data directory
+    NamedSet(PathBuf, String),
+    /// A file anywhere on disk
+    Path(PathBuf),
+    Stdin,
+}
+
+impl InputSource {
+    /// Parses the value of an `--input` flag, where `-` means standard input
+    pub fn from_argument(argument: &str) -> InputSource {
+        match argument {
+            "-" => InputSource::Stdin,
+            path => InputSource::Path(PathBuf::from(path)),
+        }
+    }
+
+    /// Short name used when tabulating answers from several inputs
+    pub fn label(&self) -> String {
+        match self {
+            InputSource::DataFile(_) => String::from(DEFAULT_SET),
+            InputSource::NamedSet(_, name) => name.clone(),
+            InputSource::Path(path) => path.display().to_string(),
+            InputSource::Stdin => String::from("stdin"),
+        }
+    }
+}
+
+/// Puzzle input loaded for a particular day
+#[derive(Debug)]
+pub struct Input {
+    pub day: u8,
+    pub path: PathBuf,
+    pub text: String,
+}
+
+#[derive(Debug)]
+pub enum InputError {
+    /// The day was not a number between 1 and 25
+    BadDay(String),
+    MissingFile(PathBuf),
+    UnreadableFile(PathBuf, io::Error),
+    EmptyFile(PathBuf),
+}
+
+impl fmt::Display for InputError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            InputError::BadDay(day) => write!(f, "{day} is not a day between 1 and 25"),
+            InputError::MissingFile(path) => write!(f, "No input file at {}", path.display()),
+            InputError::UnreadableFile(path, error) => {
+                write!(f, "Failed to read {}: {error}", path.display())
+            }
+            InputError::EmptyFile(path) => write!(f, "Input file {} is empty", path.display()),
+        }
+    }
+}
+
+impl std::error::Error for InputError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            InputError::UnreadableFile(_, error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+/// Picks the data directory: an explicit override first, then `AOC_DATA_DIR`, then the
+/// `problems` directory of this checkout so that the binaries work from any working directory
+pub fn data_dir(explicit: Option<PathBuf>) -> PathBuf {
+    explicit
+        .or_else(|| env::var_os(DATA_DIR_VARIABLE).map(PathBuf::from))
+        .unwrap_or_else(|| Path::new(env!("CARGO_MANIFEST_DIR")).join("problems"))
+}
+
+pub fn parse_day(day: &str) -> Result<u8, InputError> {
+    match day.trim().parse::<u8>() {
+        Ok(number) if (1..=25).contains(&number) => Ok(number),
+        _ => Err(InputError::BadDay(day.to_string())),
+    }
+}
+
+pub fn load_input(day: &str, source: &InputSource) -> Result<Input, InputError> {
+    let day = parse_day(day)?;
+    let path = match source {
+        InputSource::DataFile(dir) => dir.join(format!("{day:02}_data.txt")),
+        InputSource::NamedSet(dir, name) => dir.join(format!("{day:02}_data.{name}.txt")),
+        InputSource::Path(path) => path.clone(),
+        InputSource::Stdin => {
+            let path = PathBuf::from("-");
+            let mut text = String::new();
+            io::stdin()
+                .read_to_string(&mut text)
+                .map_err(|error| InputError::UnreadableFile(path.clone(), error))?;
+            if text.trim().is_empty() {
+                return Err(InputError::EmptyFile(path));
+            }
+            return Ok(Input { day, path, text });
+        }
+    };
+    let text = read_file(&path)?;
+    Ok(Input { day, path, text })
+}
+
+/// Lists every input set available for a day in the data directory: the checked-in data first,
+/// then each `NN_data.<name>.txt` in name order
+pub fn list_input_sets(day: u8, dir: &Path) -> Result<Vec<InputSource>, InputError> {
+    let entries = fs::read_dir(dir).map_err(|error| match error.kind() {
+        io::ErrorKind::NotFound => InputError::MissingFile(dir.to_path_buf()),
+        _ => InputError::UnreadableFile(dir.to_path_buf(), error),
+    })?;
+    let prefix = format!("{day:02}_data.");
+    let mut names = Vec::new();
+    let mut has_default = false;
+    for entry in entries.flatten() {
+        let file_name = entry.file_name().to_string_lossy().to_string();
+        let Some(rest) = file_name.strip_prefix(&prefix) else {
+            continue;
+        };
+        if rest == "txt" {
+            has_default = true;
+        } else if let Some(name) = rest.strip_suffix(".txt").filter(|name| !name.is_empty()) {
+            names.push(name.to_string());
+        }
+    }
+    names.sort();
+
+    let mut sets = Vec::new();
+    if has_default {
+        sets.push(InputSource::DataFile(dir.to_path_buf()));
+    }
+    sets.extend(
+        names
+            .into_iter()
+            .map(|name| InputSource::NamedSet(dir.to_path_buf(), name)),
+    );
+    Ok(sets)
+}
+
+fn read_file(path: &Path) -> Result<String, InputError> {
+    let text = fs::read_to_string(path).map_err(|error| match error.kind() {
+        io::ErrorKind::NotFound => InputError::MissingFile(path.to_path_buf()),
+        _ => InputError::UnreadableFile(path.to_path_buf(), error),
+    })?;
+    if text.trim().is_empty() {
+        return Err(InputError::EmptyFile(path.to_path_buf()));
+    }
+    Ok(text)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn scratch_dir(name: &str) -> PathBuf {
+        let dir = env::temp_dir().join(format!("aoc-input-{name}-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    #[test]
+    fn test_load_checked_in_data() {
+        let source = InputSource::DataFile(data_dir(None));
+        let input = load_input("1", &source).unwrap();
+        assert_eq!(input.day, 1);
+        assert!(input.path.ends_with("01_data.txt"));
+        assert!(!input.text.is_empty());
+    }
+
+    #[test]
+    fn test_bad_day() {
+        let source = InputSource::DataFile(data_dir(None));
+        assert!(matches!(
+            load_input("26", &source),
+            Err(InputError::BadDay(_))
+        ));
+        assert!(matches!(
+            load_input("0", &source),
+            Err(InputError::BadDay(_))
+        ));
+        assert!(matches!(
+            load_input("seven", &source),
+            Err(InputError::BadDay(_))
+        ));
+    }
+
+    #[test]
+    fn test_missing_file() {
+        let source = InputSource::DataFile(scratch_dir("missing"));
+        assert!(matches!(
+            load_input("07", &source),
+            Err(InputError::MissingFile(_))
+        ));
+    }
+
+    #[test]
+    fn test_empty_file() {
+        let dir = scratch_dir("empty");
+        fs::write(dir.join("07_data.txt"), "\n").unwrap();
+        let source = InputSource::DataFile(dir);
+        assert!(matches!(
+            load_input("07", &source),
+            Err(InputError::EmptyFile(_))
+        ));
+    }
+
+    #[test]
+    fn test_named_sets_and_paths() {
+        let dir = scratch_dir("named");
+        fs::write(dir.join("07_data.txt"), "default").unwrap();
+        fs::write(dir.join("07_data.bob.txt"), "bob").unwrap();
+        fs::write(dir.join("07_data.alice.txt"), "alice").unwrap();
+        fs::write(dir.join("07_description.txt"), "not an input").unwrap();
+        fs::write(dir.join("08_data.alice.txt"), "another day").unwrap();
+
+        let sets = list_input_sets(7, &dir).unwrap();
+        let labels = sets.iter().map(|set| set.label()).collect::<Vec<_>>();
+        assert_eq!(labels, vec!["default", "alice", "bob"]);
+
+        let alice = load_input("7", &sets[1]).unwrap();
+        assert_eq!(alice.text, "alice");
+        let by_path = load_input(
+            "7",
+            &InputSource::from_argument(dir.join("07_data.bob.txt").to_str().unwrap()),
+        )
+        .unwrap();
+        assert_eq!(by_path.text, "bob");
+        assert_eq!(InputSource::from_argument("-"), InputSource::Stdin);
+    }
+
+    #[test]
+    fn test_unreadable_file() {
+        // A directory where the file should be cannot be read as text
+        let dir = scratch_dir("unreadable");
+        fs::create_dir_all(dir.join("07_data.txt")).unwrap();
+        let source = InputSource::DataFile(dir);
+        assert!(matches!(
+            load_input("07", &source),
+            Err(InputError::UnreadableFile(_, _))
+        ));
+    }
+}
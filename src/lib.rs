@@ -1,13 +1,353 @@
-use std::fs;
+use std::{fmt, process::ExitCode};
 
-pub fn read_today_data_file<'a>(day: String) -> String {
-    let path = format!("./problems/{day}_data.txt");
-    let file: String = read_file(&path);
-    file
+use animation::Recorder;
+use answer::{Answer, SolveError, Solved};
+use input::{data_dir, load_input, Input, InputSource};
+use params::{ParamError, Parameters, Settings};
+use parse::ParseError;
+use render::Canvas;
+use svg::Figure;
+
+pub mod animation;
+pub mod answer;
+pub mod answers;
+pub mod bench;
+pub mod budget;
+pub mod cycles;
+pub mod examples;
+pub mod geometry;
+pub mod grid;
+pub mod input;
+pub mod memo;
+pub mod number_theory;
+pub mod params;
+pub mod parse;
+pub mod pathfinding;
+pub mod pool;
+pub mod regions;
+pub mod render;
+pub mod svg;
+pub mod table;
+pub mod trace;
+pub mod union_find;
+
+pub mod day01;
+pub mod day02;
+pub mod day03;
+pub mod day04;
+pub mod day05;
+pub mod day06;
+pub mod day07;
+pub mod day08;
+pub mod day09;
+pub mod day10;
+pub mod day11;
+pub mod day12;
+pub mod day13;
+pub mod day14;
+pub mod day15;
+pub mod day16;
+pub mod day17;
+pub mod day18;
+pub mod day19;
+pub mod day20;
+pub mod day21;
+pub mod day22;
+pub mod day23;
+pub mod day24;
+pub mod day25;
+
+/// Common interface implemented by every day's solver
+pub trait Solution {
+    type Params: Parameters;
+
+    /// Parses the input on its own so that parsing can be timed separately from the parts, and so
+    /// that malformed input is reported before solving. Days whose parsing is interleaved with
+    /// solving keep the default, which accepts anything
+    fn parse(_input: &str) -> Result<(), ParseError> {
+        Ok(())
+    }
+    fn part_one(input: &str, params: &Self::Params) -> Solved;
+    fn part_two(input: &str, params: &Self::Params) -> Solved;
+
+    /// Draws a picture of the puzzle, such as the path that was taken through a map. Days with
+    /// nothing worth seeing keep the default
+    fn render(_input: &str, _params: &Self::Params) -> Option<Canvas> {
+        None
+    }
+
+    /// Records a picture after each step of a simulation, returning whether the day has one
+    fn animate(_input: &str, _params: &Self::Params, _recorder: &mut Recorder) -> bool {
+        false
+    }
+
+    /// Vector drawings of the routes the day finds, for including in write-ups
+    fn figures(_input: &str, _params: &Self::Params) -> Vec<Figure> {
+        Vec::new()
+    }
+}
+
+fn solve_with<S: Solution>(
+    input: &str,
+    part: Part,
+    settings: &Settings,
+) -> Result<Answer, RunError> {
+    let params = S::Params::configure(settings)?;
+    let solved = match part {
+        Part::One => S::part_one(input, &params),
+        Part::Two => S::part_two(input, &params),
+    };
+    Ok(solved?)
+}
+
+fn render_with<S: Solution>(
+    input: &str,
+    settings: &Settings,
+) -> Result<Option<Canvas>, ParamError> {
+    let params = S::Params::configure(settings)?;
+    Ok(S::render(input, &params))
+}
+
+fn animate_with<S: Solution>(
+    input: &str,
+    settings: &Settings,
+    recorder: &mut Recorder,
+) -> Result<bool, ParamError> {
+    let params = S::Params::configure(settings)?;
+    Ok(S::animate(input, &params, recorder))
 }
 
-fn read_file(path: &String) -> String {
-    // Returns all contents of a file
-    let contents: String = fs::read_to_string(path).expect("Failed to read file {path}");
-    return contents;
+fn figures_with<S: Solution>(input: &str, settings: &Settings) -> Result<Vec<Figure>, ParamError> {
+    let params = S::Params::configure(settings)?;
+    Ok(S::figures(input, &params))
+}
+
+fn check_settings<S: Solution>(settings: &Settings) -> Result<(), ParamError> {
+    S::Params::configure(settings).map(|_| ())
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl fmt::Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Part::One => write!(f, "1"),
+            Part::Two => write!(f, "2"),
+        }
+    }
+}
+
+/// A single entry in the calendar, pointing at the solver for that day
+pub struct Day {
+    pub number: u8,
+    parse: fn(&str) -> Result<(), ParseError>,
+    solve_with: fn(&str, Part, &Settings) -> Result<Answer, RunError>,
+    check_settings: fn(&Settings) -> Result<(), ParamError>,
+    render_with: fn(&str, &Settings) -> Result<Option<Canvas>, ParamError>,
+    animate_with: fn(&str, &Settings, &mut Recorder) -> Result<bool, ParamError>,
+    figures_with: fn(&str, &Settings) -> Result<Vec<Figure>, ParamError>,
+}
+
+impl Day {
+    const fn new<S: Solution>(number: u8) -> Day {
+        Day {
+            number,
+            parse: S::parse,
+            solve_with: solve_with::<S>,
+            check_settings: check_settings::<S>,
+            render_with: render_with::<S>,
+            animate_with: animate_with::<S>,
+            figures_with: figures_with::<S>,
+        }
+    }
+
+    pub fn parse(&self, input: &str) -> Result<(), ParseError> {
+        (self.parse)(input)
+    }
+
+    /// Solves a part with the day's default parameters
+    pub fn solve(&self, input: &str, part: Part) -> Solved {
+        match self.solve_with(input, part, &Settings::default()) {
+            Ok(answer) => Ok(answer),
+            Err(RunError::Solve(error)) => Err(error),
+            Err(error) => panic!("default parameters are always valid: {error}"),
+        }
+    }
+
+    /// Solves a part with the day's parameters overridden by `settings`
+    pub fn solve_with(
+        &self,
+        input: &str,
+        part: Part,
+        settings: &Settings,
+    ) -> Result<Answer, RunError> {
+        (self.solve_with)(input, part, settings)
+    }
+
+    /// Checks that `settings` only sets parameters the day has, to valid values
+    pub fn check_settings(&self, settings: &Settings) -> Result<(), ParamError> {
+        (self.check_settings)(settings)
+    }
+
+    /// Draws the day's picture with its parameters overridden by `settings`, or `None` if the day
+    /// has nothing to draw
+    pub fn render_with(
+        &self,
+        input: &str,
+        settings: &Settings,
+    ) -> Result<Option<Canvas>, ParamError> {
+        (self.render_with)(input, settings)
+    }
+
+    /// Records the day's simulation step by step with its parameters overridden by `settings`,
+    /// returning whether the day has a simulation to record
+    pub fn animate_with(
+        &self,
+        input: &str,
+        settings: &Settings,
+        recorder: &mut Recorder,
+    ) -> Result<bool, ParamError> {
+        (self.animate_with)(input, settings, recorder)
+    }
+
+    /// Draws the day's figures with its parameters overridden by `settings`. Empty if the day has
+    /// no routes to draw
+    pub fn figures_with(
+        &self,
+        input: &str,
+        settings: &Settings,
+    ) -> Result<Vec<Figure>, ParamError> {
+        (self.figures_with)(input, settings)
+    }
+}
+
+pub const DAYS: [Day; 25] = [
+    Day::new::<day01::Solver>(1),
+    Day::new::<day02::Solver>(2),
+    Day::new::<day03::Solver>(3),
+    Day::new::<day04::Solver>(4),
+    Day::new::<day05::Solver>(5),
+    Day::new::<day06::Solver>(6),
+    Day::new::<day07::Solver>(7),
+    Day::new::<day08::Solver>(8),
+    Day::new::<day09::Solver>(9),
+    Day::new::<day10::Solver>(10),
+    Day::new::<day11::Solver>(11),
+    Day::new::<day12::Solver>(12),
+    Day::new::<day13::Solver>(13),
+    Day::new::<day14::Solver>(14),
+    Day::new::<day15::Solver>(15),
+    Day::new::<day16::Solver>(16),
+    Day::new::<day17::Solver>(17),
+    Day::new::<day18::Solver>(18),
+    Day::new::<day19::Solver>(19),
+    Day::new::<day20::Solver>(20),
+    Day::new::<day21::Solver>(21),
+    Day::new::<day22::Solver>(22),
+    Day::new::<day23::Solver>(23),
+    Day::new::<day24::Solver>(24),
+    Day::new::<day25::Solver>(25),
+];
+
+pub fn get_day(number: u8) -> Option<&'static Day> {
+    DAYS.iter().find(|day| day.number == number)
+}
+
+/// Why a day could not be run
+#[derive(Debug, PartialEq)]
+pub enum RunError {
+    Params(ParamError),
+    Input(ParseError),
+    /// A part finished without an answer
+    Solve(SolveError),
+}
+
+impl fmt::Display for RunError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            RunError::Params(error) => write!(f, "{error}"),
+            RunError::Input(error) => write!(f, "Malformed input: {error}"),
+            RunError::Solve(error) => write!(f, "{error}"),
+        }
+    }
+}
+
+impl std::error::Error for RunError {}
+
+impl From<ParamError> for RunError {
+    fn from(error: ParamError) -> RunError {
+        RunError::Params(error)
+    }
+}
+
+impl From<SolveError> for RunError {
+    fn from(error: SolveError) -> RunError {
+        RunError::Solve(error)
+    }
+}
+
+impl From<ParseError> for RunError {
+    fn from(error: ParseError) -> RunError {
+        RunError::Input(error)
+    }
+}
+
+/// Runs the given parts of a day against its input, printing each answer on its own line. The
+/// settings and the input are both checked before anything is solved
+pub fn run_day(
+    day: &Day,
+    input: &Input,
+    parts: &[Part],
+    settings: &Settings,
+) -> Result<(), RunError> {
+    day.check_settings(settings)?;
+    day.parse(&input.text)?;
+    for &part in parts {
+        let answer = day.solve_with(&input.text, part, settings)?;
+        println!("Day {:02} part {part}: {answer}", day.number);
+    }
+    Ok(())
+}
+
+/// Entry point for the per-day binaries, which run both parts against the checked-in data. They
+/// take the same -v, --trace and --quiet flags as the main binary
+pub fn day_main(number: u8) -> ExitCode {
+    let mut filter = trace::Filter::default();
+    let mut args = std::env::args().skip(1);
+    while let Some(arg) = args.next() {
+        match filter.apply_flag(&arg, &mut args) {
+            Ok(true) => (),
+            Ok(false) => {
+                eprintln!("Unrecognised argument {arg}");
+                return ExitCode::FAILURE;
+            }
+            Err(error) => {
+                eprintln!("{error}");
+                return ExitCode::FAILURE;
+            }
+        }
+    }
+    trace::set_filter(filter);
+
+    let source = InputSource::DataFile(data_dir(None));
+    match load_input(&number.to_string(), &source) {
+        Ok(input) => {
+            let day = &DAYS[input.day as usize - 1];
+            match run_day(day, &input, &[Part::One, Part::Two], &Settings::default()) {
+                Ok(()) => ExitCode::SUCCESS,
+                Err(error) => {
+                    eprintln!("Day {number:02}: {error}");
+                    ExitCode::FAILURE
+                }
+            }
+        }
+        Err(error) => {
+            eprintln!("{error}");
+            ExitCode::FAILURE
+        }
+    }
 }
@@ -1,3 +1,723 @@
+use std::{env, fs, path::PathBuf, process};
+
+use advent_of_code_2024::{
+    animation::Recorder,
+    answers::{describe_difference, Answers, Verdict, ANSWERS_FILE},
+    bench::{self, Format, DEFAULT_ITERATIONS},
+    examples::{description_path, extract, fixture_path, is_hand_maintained, to_fixture},
+    get_day,
+    input::{data_dir, list_input_sets, load_input, parse_day, Input, InputSource},
+    params::{Settings, PARAMETERS},
+    pool::{default_workers, run_jobs, Job, Outcome},
+    regions::Bounds,
+    run_day,
+    table::Table,
+    trace::{self, Filter},
+    Day, Part, RunError, DAYS,
+};
+
+/// Pixels across each cell of a saved picture
+const DEFAULT_SCALE: usize = 4;
+
+/// Hundredths of a second each frame of an animation is shown for
+const DEFAULT_DELAY: u16 = 5;
+
+/// Where route figures are saved unless --output says otherwise
+const DEFAULT_FIGURES: &str = "figures";
+
+const USAGE: &str = "Usage: aoc run <day|all> [--part <1|2>] [--data-dir <path>]
+                    [--input <path|->] [--set <name>] [--jobs <n>] [<parameters>]
+       aoc batch <day> [--part <1|2>] [--data-dir <path>] [<parameters>]
+       aoc verify <day|all> [--part <1|2>] [--data-dir <path>]
+       aoc bench <day|all> [--part <1|2>] [--data-dir <path>] [--input <path|->] [--set <name>]
+                           [--iterations <n>] [--format <table|json>] [<parameters>]
+       aoc examples <day|all> [--data-dir <path>] [--force]
+       aoc render <day> [--data-dir <path>] [--input <path|->] [--set <name>]
+                        [--output <path.png|path.ppm>] [--scale <n>] [<parameters>]
+       aoc animate <day> --output <path.gif|directory> [--every <n>]
+                         [--crop <left,top,right,bottom>] [--delay <n>] [--scale <n>]
+                         [--data-dir <path>] [--input <path|->] [--set <name>] [<parameters>]
+       aoc svg <day|all> [--output <directory>] [--data-dir <path>] [--input <path|->]
+                         [--set <name>] [<parameters>]
+
+Every command also takes -v for more diagnostics (-vv, -vvv for more still), --trace <target>
+for everything from one day, such as --trace day11, or --trace day11=debug for up to a level, and
+--quiet for none. Diagnostics go to standard error; by default only warnings are shown.
+Parameters override a day's puzzle constants for a single day: --width <n> (days 14 and 18),
+--height <n> and --seconds <n> (day 14), --bytes <n> (day 18), --blinks <n> (day 11),
+--threshold <n> (day 20) and --budget <n> (days 5 and 17), the most steps a long-running loop
+may take before the part fails rather than give a truncated answer.
+The data directory defaults to $AOC_DATA_DIR, then to the problems directory of this checkout.
+--input reads a file, or standard input for -, instead of the day's NN_data.txt.
+--set reads the named input set NN_data.<name>.txt from the data directory.
+run all solves the days on --jobs threads (default one per CPU) and prints a summary; a day that
+panics is reported without stopping the others.
+Every input is parsed before it is solved, and malformed input is reported by line and column.
+batch runs every input set for a day and tabulates the answers.
+verify checks the answers for every input set against answers.txt in the data directory.
+bench times parsing and each part over --iterations runs (default 10), reporting min, median and
+max; parts are timed end to end, including their own parsing.
+examples extracts the worked examples from NN_description.txt into examples/NN.txt in the data
+directory, keeping existing fixtures unless --force is given. Fixtures starting with
+a # hand-maintained comment are kept even then.
+render draws a day's picture, such as the guard's path on day 6, in colour on the terminal, or
+saves it as an image with --output, each cell --scale pixels across (default 4). Days 6, 12, 14,
+15 and 16 have pictures.
+animate records a day's simulation step by step as a looping GIF, or as numbered PNGs in a
+directory, keeping every --every'th step (default 1) cropped to the inclusive --crop corners and
+showing each frame for --delay hundredths of a second (default 5). Days 6, 14 and 15 animate.
+svg draws the routes found on days 16, 18 and 20 as NN_<figure>.svg files in the --output
+directory (default figures).";
+
+struct Options {
+    target: String,
+    parts: Vec<Part>,
+    data_dir: PathBuf,
+    input: Option<InputSource>,
+    iterations: usize,
+    format: Format,
+    force: bool,
+    workers: usize,
+    output: Option<PathBuf>,
+    scale: usize,
+    every: usize,
+    crop: Option<Bounds>,
+    delay: u16,
+    trace: Filter,
+    settings: Settings,
+}
+
+fn parse_part(value: Option<String>) -> Result<Vec<Part>, String> {
+    match value.as_deref() {
+        Some("1") => Ok(vec![Part::One]),
+        Some("2") => Ok(vec![Part::Two]),
+        Some(other) => Err(format!("Unrecognised part {other}")),
+        None => Err(String::from("--part requires a value")),
+    }
+}
+
+fn parse_crop(value: Option<String>) -> Result<Bounds, String> {
+    let value = value.ok_or("--crop requires a value")?;
+    let invalid = || format!("Crop must be left,top,right,bottom, not {value}");
+    let corners = value
+        .split(',')
+        .map(|n| n.trim().parse::<usize>())
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|_| invalid())?;
+    match corners[..] {
+        [left, top, right, bottom] if left <= right && top <= bottom => Ok(Bounds {
+            left,
+            top,
+            right,
+            bottom,
+        }),
+        _ => Err(invalid()),
+    }
+}
+
+fn parse_options(mut args: impl Iterator<Item = String>) -> Result<Options, String> {
+    let target = args.next().ok_or("No day given")?;
+    let mut parts = vec![Part::One, Part::Two];
+    let mut explicit_data_dir = None;
+    let mut input_argument = None;
+    let mut set = None;
+    let mut iterations = DEFAULT_ITERATIONS;
+    let mut format = Format::Table;
+    let mut force = false;
+    let mut workers = default_workers();
+    let mut output = None;
+    let mut scale = DEFAULT_SCALE;
+    let mut every = 1;
+    let mut crop = None;
+    let mut delay = DEFAULT_DELAY;
+    let mut trace = Filter::default();
+    let mut settings = Settings::default();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--part" | "-p" => parts = parse_part(args.next())?,
+            "--data-dir" => {
+                let dir = args.next().ok_or("--data-dir requires a value")?;
+                explicit_data_dir = Some(PathBuf::from(dir));
+            }
+            "--input" | "-i" => {
+                input_argument = Some(args.next().ok_or("--input requires a value")?)
+            }
+            "--set" => set = Some(args.next().ok_or("--set requires a value")?),
+            "--iterations" | "-n" => {
+                let value = args.next().ok_or("--iterations requires a value")?;
+                iterations = match value.parse::<usize>() {
+                    Ok(n) if n > 0 => n,
+                    _ => return Err(format!("Iterations must be a positive number, not {value}")),
+                };
+            }
+            "--force" => force = true,
+            "--jobs" | "-j" => {
+                let value = args.next().ok_or("--jobs requires a value")?;
+                workers = match value.parse::<usize>() {
+                    Ok(n) if n > 0 => n,
+                    _ => return Err(format!("Jobs must be a positive number, not {value}")),
+                };
+            }
+            "--output" | "-o" => {
+                output = Some(PathBuf::from(
+                    args.next().ok_or("--output requires a value")?,
+                ))
+            }
+            "--scale" => {
+                let value = args.next().ok_or("--scale requires a value")?;
+                scale = match value.parse::<usize>() {
+                    Ok(n) if n > 0 => n,
+                    _ => return Err(format!("Scale must be a positive number, not {value}")),
+                };
+            }
+            "--every" => {
+                let value = args.next().ok_or("--every requires a value")?;
+                every = match value.parse::<usize>() {
+                    Ok(n) if n > 0 => n,
+                    _ => return Err(format!("Every must be a positive number, not {value}")),
+                };
+            }
+            "--crop" => crop = Some(parse_crop(args.next())?),
+            "--delay" => {
+                let value = args.next().ok_or("--delay requires a value")?;
+                delay = value
+                    .parse::<u16>()
+                    .map_err(|_| format!("Delay must be a number, not {value}"))?;
+            }
+            "--format" => format = args.next().ok_or("--format requires a value")?.parse()?,
+            flag if trace.apply_flag(flag, &mut args)? => (),
+            flag if PARAMETERS.contains(&flag.trim_start_matches("--")) => {
+                let name = flag.trim_start_matches("--");
+                let value = args.next().ok_or(format!("{flag} requires a value"))?;
+                let value = value
+                    .parse::<u64>()
+                    .map_err(|_| format!("{flag} must be a number, not {value}"))?;
+                settings.set(name, value);
+            }
+            other => return Err(format!("Unrecognised argument {other}")),
+        }
+    }
+    let data_dir = data_dir(explicit_data_dir);
+    let input = match (input_argument, set) {
+        (Some(_), Some(_)) => return Err(String::from("--input and --set cannot be combined")),
+        (Some(argument), None) => Some(InputSource::from_argument(&argument)),
+        (None, Some(name)) => Some(InputSource::NamedSet(data_dir.clone(), name)),
+        (None, None) => None,
+    };
+    Ok(Options {
+        target,
+        parts,
+        data_dir,
+        input,
+        iterations,
+        format,
+        force,
+        workers,
+        output,
+        scale,
+        every,
+        crop,
+        delay,
+        trace,
+        settings,
+    })
+}
+
+fn single_day(target: &str) -> Result<&'static Day, String> {
+    let number = parse_day(target).map_err(|error| error.to_string())?;
+    get_day(number).ok_or(format!("No solution for day {target}"))
+}
+
+/// Loads a day's input and checks that it parses, so that malformed input is reported up front
+/// rather than as a panic part way through solving
+fn load_valid_input(day: &Day, source: &InputSource) -> Result<Input, String> {
+    let input = load_input(&day.number.to_string(), source).map_err(|e| e.to_string())?;
+    day.parse(&input.text)
+        .map_err(|error| RunError::from(error).to_string())?;
+    Ok(input)
+}
+
+/// Runs the requested day, returning whether it had input to run against
+fn run(options: Options) -> Result<bool, String> {
+    if options.target == "all" {
+        return run_all(options);
+    }
+    let day = single_day(&options.target)?;
+    let source = options
+        .input
+        .unwrap_or(InputSource::DataFile(options.data_dir));
+    match load_input(&day.number.to_string(), &source) {
+        Ok(input) => match run_day(day, &input, &options.parts, &options.settings) {
+            Ok(()) => Ok(true),
+            // Bad input and parts without an answer are reported like missing input, while bad
+            // parameters are a usage error
+            Err(error @ (RunError::Input(_) | RunError::Solve(_))) => {
+                eprintln!("Day {:02}: {error}", day.number);
+                Ok(false)
+            }
+            Err(error) => Err(format!("Day {:02}: {error}", day.number)),
+        },
+        Err(error) => {
+            eprintln!("Day {:02}: {error}", day.number);
+            Ok(false)
+        }
+    }
+}
+
+/// Solves every day concurrently and prints a summary grid, returning whether every day had
+/// input and none of them panicked
+fn run_all(options: Options) -> Result<bool, String> {
+    if options.input.is_some() || !options.settings.is_empty() {
+        return Err(String::from(
+            "--input, --set and parameters need a single day",
+        ));
+    }
+    let source = InputSource::DataFile(options.data_dir);
+    let inputs = DAYS
+        .iter()
+        .map(|day| {
+            let input = load_input(&day.number.to_string(), &source);
+            let input = match input {
+                Ok(input) => match day.parse(&input.text) {
+                    Ok(()) => Ok(input),
+                    Err(error) => Err((RunError::from(error).to_string(), "malformed input")),
+                },
+                Err(error) => Err((error.to_string(), "no input")),
+            };
+            (day, input)
+        })
+        .collect::<Vec<_>>();
+
+    let jobs = inputs
+        .iter()
+        .filter_map(|(day, input)| Some((day, &input.as_ref().ok()?.text)))
+        .flat_map(|(day, input)| options.parts.iter().map(|&part| Job { day, part, input }))
+        .collect::<Vec<Job>>();
+    let mut runs = run_jobs(&jobs, options.workers).into_iter().peekable();
+
+    let mut table = Table::new(&["Day", "Part", "Answer", "Time", "Status"]);
+    let mut all_solved = true;
+    for (day, input) in &inputs {
+        for &part in &options.parts {
+            let mut row = vec![format!("{:02}", day.number), part.to_string()];
+            if let Err((error, status)) = input {
+                eprintln!("Day {:02}: {error}", day.number);
+                all_solved = false;
+                row.extend([String::new(), String::new(), status.to_string()]);
+                table.add_row(row);
+                continue;
+            }
+            let run = runs.next().expect("a run for every job");
+            let time = format!("{:.2?}", run.elapsed);
+            match run.outcome {
+                Outcome::Solved(answer) => {
+                    row.extend([answer.to_string(), time, String::from("ok")])
+                }
+                Outcome::Panicked(message) => {
+                    all_solved = false;
+                    row.extend([String::new(), time, format!("panicked: {message}")]);
+                }
+                Outcome::Failed(error) => {
+                    all_solved = false;
+                    row.extend([String::new(), time, format!("failed: {error}")]);
+                }
+            }
+            table.add_row(row);
+        }
+    }
+    print!("{table}");
+    Ok(all_solved)
+}
+
+/// Runs every input set of a single day, printing one row of answers per set
+fn batch(options: Options) -> Result<bool, String> {
+    if options.input.is_some() {
+        return Err(String::from("batch already runs every input set"));
+    }
+    let day = single_day(&options.target)?;
+    day.check_settings(&options.settings)
+        .map_err(|error| format!("Day {:02}: {error}", day.number))?;
+    let sets = list_input_sets(day.number, &options.data_dir).map_err(|e| e.to_string())?;
+    if sets.is_empty() {
+        return Err(format!(
+            "No input sets for day {:02} in {}",
+            day.number,
+            options.data_dir.display()
+        ));
+    }
+
+    let mut headers = vec![String::from("Set")];
+    headers.extend(options.parts.iter().map(|part| format!("Part {part}")));
+    let mut table = Table::new(&headers);
+    let mut all_solved = true;
+    for set in sets {
+        let mut row = vec![set.label()];
+        match load_valid_input(day, &set) {
+            Ok(input) => {
+                for &part in &options.parts {
+                    match day.solve_with(&input.text, part, &options.settings) {
+                        Ok(answer) => row.push(answer.to_string()),
+                        Err(RunError::Solve(error)) => {
+                            eprintln!(
+                                "Day {:02} set {} part {part}: {error}",
+                                day.number,
+                                set.label()
+                            );
+                            all_solved = false;
+                            row.push(String::new());
+                        }
+                        Err(error) => return Err(error.to_string()),
+                    }
+                }
+            }
+            Err(error) => {
+                eprintln!("Day {:02} set {}: {error}", day.number, set.label());
+                all_solved = false;
+            }
+        }
+        table.add_row(row);
+    }
+    print!("{table}");
+    Ok(all_solved)
+}
+
+/// Checks every input set of the requested days against the known-answer registry, returning
+/// whether nothing failed
+fn verify(options: Options) -> Result<bool, String> {
+    if options.input.is_some() {
+        return Err(String::from("verify already runs every input set"));
+    }
+    if !options.settings.is_empty() {
+        return Err(String::from(
+            "verify checks the answers for the default parameters",
+        ));
+    }
+    let days = if options.target == "all" {
+        DAYS.iter().collect::<Vec<_>>()
+    } else {
+        vec![single_day(&options.target)?]
+    };
+    let answers = Answers::load(&options.data_dir.join(ANSWERS_FILE)).map_err(|e| e.to_string())?;
+
+    let mut table = Table::new(&["Day", "Part", "Set", "Status", "Answer", "Expected", "Diff"]);
+    let (mut passed, mut unchanged, mut failed, mut missing) = (0, 0, 0, 0);
+    for day in days {
+        let sets = list_input_sets(day.number, &options.data_dir).map_err(|e| e.to_string())?;
+        for set in sets {
+            let input = match load_valid_input(day, &set) {
+                Ok(input) => input,
+                Err(error) => {
+                    eprintln!("Day {:02} set {}: {error}", day.number, set.label());
+                    failed += 1;
+                    continue;
+                }
+            };
+            for &part in &options.parts {
+                let mut row = vec![format!("{:02}", day.number), part.to_string(), set.label()];
+                let answer = match day.solve(&input.text, part) {
+                    Ok(answer) => answer,
+                    Err(error) => {
+                        failed += 1;
+                        row.extend([String::from("FAIL"), String::new(), String::new()]);
+                        row.push(error.to_string());
+                        table.add_row(row);
+                        continue;
+                    }
+                };
+                match answers.check(day.number, part, &set.label(), &answer) {
+                    Verdict::Pass => {
+                        passed += 1;
+                        row.extend([String::from("PASS"), answer.to_string()]);
+                    }
+                    Verdict::Unchanged => {
+                        unchanged += 1;
+                        row.extend([String::from("UNCHANGED"), answer.to_string()]);
+                    }
+                    Verdict::Fail { expected } => {
+                        failed += 1;
+                        let difference = describe_difference(&expected, &answer);
+                        row.extend([
+                            String::from("FAIL"),
+                            answer.to_string(),
+                            expected.to_string(),
+                            difference,
+                        ]);
+                    }
+                    Verdict::Missing => {
+                        missing += 1;
+                        row.extend([String::from("MISSING"), answer.to_string()]);
+                    }
+                }
+                table.add_row(row);
+            }
+        }
+    }
+    print!("{table}");
+    println!(
+        "{passed} passed, {unchanged} unchanged from recorded, {failed} failed, {missing} missing"
+    );
+    Ok(failed == 0)
+}
+
+/// Times the requested days, printing the measurements as a table or as JSON
+fn bench(options: Options) -> Result<bool, String> {
+    let days = if options.target == "all" {
+        if options.input.is_some() || !options.settings.is_empty() {
+            return Err(String::from(
+                "--input, --set and parameters need a single day",
+            ));
+        }
+        DAYS.iter().collect::<Vec<_>>()
+    } else {
+        let day = single_day(&options.target)?;
+        day.check_settings(&options.settings)
+            .map_err(|error| format!("Day {:02}: {error}", day.number))?;
+        vec![day]
+    };
+    let source = options
+        .input
+        .unwrap_or(InputSource::DataFile(options.data_dir));
+
+    let mut measurements = Vec::new();
+    let mut all_loaded = true;
+    for day in days {
+        match load_valid_input(day, &source) {
+            Ok(input) => measurements.extend(bench::bench_day(
+                day,
+                &input.text,
+                &options.parts,
+                options.iterations,
+                &options.settings,
+            )),
+            Err(error) => {
+                eprintln!("Day {:02}: {error}", day.number);
+                all_loaded = false;
+            }
+        }
+    }
+    match options.format {
+        Format::Table => print!("{}", bench::to_table(&measurements)),
+        Format::Json => print!("{}", bench::to_json(&measurements)),
+    }
+    Ok(all_loaded)
+}
+
+/// Writes an example fixture for each requested day from its puzzle description. Fixtures are
+/// meant to be checked over and corrected by hand, so existing ones are kept unless forced, and
+/// ones marked as hand-maintained are always kept
+fn write_examples(options: Options) -> Result<bool, String> {
+    let days = if options.target == "all" {
+        (1..=25).collect::<Vec<u8>>()
+    } else {
+        vec![single_day(&options.target)?.number]
+    };
+    let mut all_written = true;
+    for day in days {
+        let fixture = fixture_path(&options.data_dir, day);
+        if fs::read_to_string(&fixture).is_ok_and(|text| is_hand_maintained(&text)) {
+            println!(
+                "Day {day:02}: keeping hand-maintained {}",
+                fixture.display()
+            );
+            continue;
+        }
+        if fixture.exists() && !options.force {
+            println!("Day {day:02}: keeping {}", fixture.display());
+            continue;
+        }
+        let description = match fs::read_to_string(description_path(&options.data_dir, day)) {
+            Ok(description) => description,
+            Err(error) => {
+                eprintln!("Day {day:02}: no description: {error}");
+                all_written = false;
+                continue;
+            }
+        };
+        let examples = extract(&description);
+        if examples.is_empty() {
+            println!("Day {day:02}: no examples found");
+            continue;
+        }
+        let written = fixture
+            .parent()
+            .map_or(Ok(()), fs::create_dir_all)
+            .and_then(|_| fs::write(&fixture, to_fixture(&examples)));
+        match written {
+            Ok(()) => println!(
+                "Day {day:02}: wrote {} example(s) to {}",
+                examples.len(),
+                fixture.display()
+            ),
+            Err(error) => {
+                eprintln!(
+                    "Day {day:02}: failed to write {}: {error}",
+                    fixture.display()
+                );
+                all_written = false;
+            }
+        }
+    }
+    Ok(all_written)
+}
+
+/// Draws a single day's picture on the terminal, or saves it as an image
+fn render(options: Options) -> Result<bool, String> {
+    let day = single_day(&options.target)?;
+    let source = options
+        .input
+        .unwrap_or(InputSource::DataFile(options.data_dir));
+    let input = match load_valid_input(day, &source) {
+        Ok(input) => input,
+        Err(error) => {
+            eprintln!("Day {:02}: {error}", day.number);
+            return Ok(false);
+        }
+    };
+    let canvas = day
+        .render_with(&input.text, &options.settings)
+        .map_err(|error| format!("Day {:02}: {error}", day.number))?;
+    let Some(canvas) = canvas else {
+        eprintln!("Day {:02} has nothing to render", day.number);
+        return Ok(false);
+    };
+    match options.output {
+        Some(path) => {
+            let image = canvas.to_image(options.scale);
+            image
+                .save(&path)
+                .map_err(|error| format!("Failed to write {}: {error}", path.display()))?;
+            println!(
+                "Day {:02}: wrote a {}x{} image to {}",
+                day.number,
+                image.width,
+                image.height,
+                path.display()
+            );
+        }
+        None => print!("{}", canvas.to_ansi()),
+    }
+    Ok(true)
+}
+
+/// Records a single day's simulation and saves it as an animation
+fn animate(options: Options) -> Result<bool, String> {
+    let day = single_day(&options.target)?;
+    let path = options.output.ok_or("animate needs an --output")?;
+    let source = options
+        .input
+        .unwrap_or(InputSource::DataFile(options.data_dir));
+    let input = match load_valid_input(day, &source) {
+        Ok(input) => input,
+        Err(error) => {
+            eprintln!("Day {:02}: {error}", day.number);
+            return Ok(false);
+        }
+    };
+    let mut recorder = Recorder::new(options.every);
+    if let Some(crop) = options.crop {
+        recorder = recorder.cropped(crop);
+    }
+    let animated = day
+        .animate_with(&input.text, &options.settings, &mut recorder)
+        .map_err(|error| format!("Day {:02}: {error}", day.number))?;
+    if !animated {
+        eprintln!("Day {:02} has nothing to animate", day.number);
+        return Ok(false);
+    }
+    if recorder.frames().is_empty() {
+        eprintln!("Day {:02}: the crop is outside the picture", day.number);
+        return Ok(false);
+    }
+    let written = recorder
+        .save(&path, options.scale, options.delay)
+        .map_err(|error| format!("Failed to write {}: {error}", path.display()))?;
+    println!(
+        "Day {:02}: recorded {} of {} steps to {} file(s) at {}",
+        day.number,
+        recorder.frames().len(),
+        recorder.steps(),
+        written.len(),
+        path.display()
+    );
+    Ok(true)
+}
+
+/// Saves the route figures of the requested days as SVG files in the output directory
+fn write_figures(options: Options) -> Result<bool, String> {
+    let days = if options.target == "all" {
+        if options.input.is_some() || !options.settings.is_empty() {
+            return Err(String::from(
+                "--input, --set and parameters need a single day",
+            ));
+        }
+        DAYS.iter().collect::<Vec<_>>()
+    } else {
+        vec![single_day(&options.target)?]
+    };
+    let directory = options
+        .output
+        .unwrap_or_else(|| PathBuf::from(DEFAULT_FIGURES));
+    let source = options
+        .input
+        .unwrap_or(InputSource::DataFile(options.data_dir));
+    fs::create_dir_all(&directory)
+        .map_err(|error| format!("Failed to create {}: {error}", directory.display()))?;
+
+    let mut all_drawn = true;
+    for day in days {
+        let input = match load_valid_input(day, &source) {
+            Ok(input) => input,
+            Err(error) => {
+                eprintln!("Day {:02}: {error}", day.number);
+                all_drawn = false;
+                continue;
+            }
+        };
+        let figures = day
+            .figures_with(&input.text, &options.settings)
+            .map_err(|error| format!("Day {:02}: {error}", day.number))?;
+        if figures.is_empty() && options.target != "all" {
+            eprintln!("Day {:02} has no routes to draw", day.number);
+            all_drawn = false;
+        }
+        for figure in figures {
+            let path = directory.join(format!("{:02}_{}.svg", day.number, figure.name));
+            figure
+                .drawing
+                .save(&path)
+                .map_err(|error| format!("Failed to write {}: {error}", path.display()))?;
+            println!("Day {:02}: wrote {}", day.number, path.display());
+        }
+    }
+    Ok(all_drawn)
+}
+
+fn dispatch(mut args: impl Iterator<Item = String>) -> Result<bool, String> {
+    let command: fn(Options) -> Result<bool, String> = match args.next().as_deref() {
+        Some("run") => run,
+        Some("batch") => batch,
+        Some("verify") => verify,
+        Some("bench") => bench,
+        Some("examples") => write_examples,
+        Some("render") => render,
+        Some("animate") => animate,
+        Some("svg") => write_figures,
+        Some(other) => return Err(format!("Unrecognised command {other}")),
+        None => return Err(String::from("No command given")),
+    };
+    let options = parse_options(args)?;
+    trace::set_filter(options.trace.clone());
+    command(options)
+}
+
 fn main() {
-    println!("Hello, world!");
+    match dispatch(env::args().skip(1)) {
+        Ok(true) => (),
+        Ok(false) => process::exit(1),
+        Err(message) => {
+            eprintln!("{message}");
+            eprintln!("{USAGE}");
+            process::exit(2);
+        }
+    }
 }
@@ -0,0 +1,186 @@
+use std::{
+    collections::{HashMap, VecDeque},
+    fmt,
+    hash::Hash,
+};
+
+/// How well a memo has been doing
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Stats {
+    pub hits: u64,
+    pub misses: u64,
+    /// Entries dropped to keep a bounded memo within its capacity
+    pub evictions: u64,
+}
+
+impl Stats {
+    /// The fraction of lookups answered from the memo, or zero before any lookups
+    pub fn hit_rate(&self) -> f64 {
+        match self.hits + self.misses {
+            0 => 0.0,
+            lookups => self.hits as f64 / lookups as f64,
+        }
+    }
+}
+
+impl fmt::Display for Stats {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{} hits, {} misses ({:.1}% hit rate), {} evicted",
+            self.hits,
+            self.misses,
+            100.0 * self.hit_rate(),
+            self.evictions
+        )
+    }
+}
+
+/// A cache of values computed for hashable keys, for memoised recursion. A bounded memo forgets
+/// its oldest entries once it is full
+#[derive(Clone, Debug)]
+pub struct Memo<K, V> {
+    values: HashMap<K, V>,
+    /// Keys in insertion order, only kept when there is a capacity to enforce
+    order: VecDeque<K>,
+    capacity: Option<usize>,
+    stats: Stats,
+}
+
+impl<K: Clone + Eq + Hash, V: Clone> Default for Memo<K, V> {
+    fn default() -> Memo<K, V> {
+        Memo::new()
+    }
+}
+
+impl<K: Clone + Eq + Hash, V: Clone> Memo<K, V> {
+    /// A memo that never forgets
+    pub fn new() -> Memo<K, V> {
+        Memo {
+            values: HashMap::new(),
+            order: VecDeque::new(),
+            capacity: None,
+            stats: Stats::default(),
+        }
+    }
+
+    /// A memo holding at most `capacity` entries. Panics if `capacity` is zero
+    pub fn bounded(capacity: usize) -> Memo<K, V> {
+        assert!(capacity > 0, "a bounded memo needs room for an entry");
+        Memo {
+            capacity: Some(capacity),
+            ..Memo::new()
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    pub fn capacity(&self) -> Option<usize> {
+        self.capacity
+    }
+
+    pub fn stats(&self) -> Stats {
+        self.stats
+    }
+
+    /// The remembered value for `key`, counting a hit or a miss
+    pub fn get(&mut self, key: &K) -> Option<V> {
+        let value = self.values.get(key).cloned();
+        match value {
+            Some(_) => self.stats.hits += 1,
+            None => self.stats.misses += 1,
+        }
+        value
+    }
+
+    /// Remembers `value` for `key`, evicting the oldest entry if a bounded memo is full, and hands
+    /// the value back
+    pub fn insert(&mut self, key: K, value: V) -> V {
+        if let Some(capacity) = self.capacity {
+            if !self.values.contains_key(&key) {
+                if self.values.len() == capacity {
+                    let oldest = self.order.pop_front().expect("a full memo has entries");
+                    self.values.remove(&oldest);
+                    self.stats.evictions += 1;
+                }
+                self.order.push_back(key.clone());
+            }
+        }
+        self.values.insert(key, value.clone());
+        value
+    }
+
+    /// The value for `key`, computing and remembering it on a miss. `compute` is given the memo so
+    /// that it can recurse through it
+    pub fn get_or_compute<F>(&mut self, key: K, compute: F) -> V
+    where
+        F: FnOnce(&mut Memo<K, V>) -> V,
+    {
+        if let Some(value) = self.get(&key) {
+            return value;
+        }
+        let value = compute(self);
+        self.insert(key, value)
+    }
+
+    /// Forgets every entry, keeping the statistics
+    pub fn clear(&mut self) {
+        self.values.clear();
+        self.order.clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn fibonacci(memo: &mut Memo<u64, u64>, n: u64) -> u64 {
+        if n < 2 {
+            return n;
+        }
+        memo.get_or_compute(n, |memo| fibonacci(memo, n - 1) + fibonacci(memo, n - 2))
+    }
+
+    #[test]
+    fn test_memoised_recursion() {
+        let mut memo = Memo::new();
+        assert_eq!(fibonacci(&mut memo, 90), 2880067194370816120);
+        assert_eq!(memo.len(), 89);
+        let stats = memo.stats();
+        assert_eq!((stats.hits, stats.misses, stats.evictions), (87, 89, 0));
+
+        memo.clear();
+        assert!(memo.is_empty());
+        assert_eq!(fibonacci(&mut memo, 10), 55);
+        assert_eq!(memo.stats().misses, 89 + 9);
+    }
+
+    #[test]
+    fn test_bounded_memo_evicts_oldest() {
+        let mut memo = Memo::bounded(2);
+        memo.insert('a', 1);
+        memo.insert('b', 2);
+        memo.insert('a', 3);
+        memo.insert('c', 4);
+        assert_eq!(memo.len(), 2);
+        assert_eq!(memo.get(&'a'), None);
+        assert_eq!(memo.get(&'b'), Some(2));
+        assert_eq!(memo.get(&'c'), Some(4));
+        assert_eq!(memo.stats().evictions, 1);
+        assert_eq!(
+            memo.stats().to_string(),
+            "2 hits, 1 misses (66.7% hit rate), 1 evicted"
+        );
+
+        // Still correct when the recursion needs more entries than fit
+        let mut small = Memo::bounded(3);
+        assert_eq!(fibonacci(&mut small, 40), 102334155);
+        assert_eq!(small.len(), 3);
+    }
+}
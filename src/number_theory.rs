@@ -0,0 +1,360 @@
+/// Bézout's identity for a pair of integers: `a * x + b * y == gcd`, with `gcd` never negative
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Bezout {
+    pub gcd: i64,
+    pub x: i64,
+    pub y: i64,
+}
+
+/// Runs the extended Euclidean algorithm in `i128`, so that nothing in it can overflow for `i64`
+/// inputs. The coefficients returned are the smallest ones, bounded by `|b / gcd|` and `|a / gcd|`
+fn bezout(a: i128, b: i128) -> (i128, i128, i128) {
+    let (mut old_r, mut r) = (a, b);
+    let (mut old_x, mut x) = (1, 0);
+    let (mut old_y, mut y) = (0, 1);
+    while r != 0 {
+        let quotient = old_r / r;
+        (old_r, r) = (r, old_r - quotient * r);
+        (old_x, x) = (x, old_x - quotient * x);
+        (old_y, y) = (y, old_y - quotient * y);
+    }
+    if old_r < 0 {
+        (-old_r, -old_x, -old_y)
+    } else {
+        (old_r, old_x, old_y)
+    }
+}
+
+/// The greatest common divisor of `a` and `b` with its Bézout coefficients. `None` only when the
+/// divisor is 2^63, which happens for `i64::MIN` paired with zero or itself
+pub fn extended_gcd(a: i64, b: i64) -> Option<Bezout> {
+    let (gcd, x, y) = bezout(a.into(), b.into());
+    Some(Bezout {
+        gcd: gcd.try_into().ok()?,
+        x: x.try_into().ok()?,
+        y: y.try_into().ok()?,
+    })
+}
+
+/// The least common multiple of two positive numbers, or `None` if it overflows
+pub fn lcm(a: i64, b: i64) -> Option<i64> {
+    if a <= 0 || b <= 0 {
+        return None;
+    }
+    let (gcd, _, _) = bezout(a.into(), b.into());
+    (i128::from(a) / gcd * i128::from(b)).try_into().ok()
+}
+
+/// The `x` in `0..modulus` with `a * x ≡ 1`, if `a` and `modulus` are coprime
+pub fn mod_inverse(a: i64, modulus: i64) -> Option<i64> {
+    if modulus <= 0 {
+        return None;
+    }
+    let (gcd, x, _) = bezout(i128::from(a).rem_euclid(modulus.into()), modulus.into());
+    if gcd != 1 {
+        return None;
+    }
+    x.rem_euclid(modulus.into()).try_into().ok()
+}
+
+/// Every integer congruent to `residue` modulo `modulus`. The residue is kept in `0..modulus`
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Congruence {
+    pub residue: i64,
+    pub modulus: i64,
+}
+
+impl Congruence {
+    /// Panics if `modulus` is not positive
+    pub fn new(residue: i64, modulus: i64) -> Congruence {
+        assert!(modulus > 0, "modulus must be positive, not {modulus}");
+        Congruence {
+            residue: residue.rem_euclid(modulus),
+            modulus,
+        }
+    }
+
+    pub fn contains(&self, value: i64) -> bool {
+        value.rem_euclid(self.modulus) == self.residue
+    }
+
+    /// The integers in both congruences, by the Chinese remainder theorem. The moduli need not be
+    /// coprime. `None` if the congruences disagree, or the combined modulus overflows
+    pub fn combine(self, other: Congruence) -> Option<Congruence> {
+        let (r1, m1) = (i128::from(self.residue), i128::from(self.modulus));
+        let (r2, m2) = (i128::from(other.residue), i128::from(other.modulus));
+        let (gcd, inverse, _) = bezout(m1, m2);
+        if (r2 - r1) % gcd != 0 {
+            return None;
+        }
+        let modulus = m1 / gcd * m2;
+        let lifts = ((r2 - r1) / gcd * inverse).rem_euclid(m2 / gcd);
+        Some(Congruence {
+            residue: (r1 + m1 * lifts).rem_euclid(modulus).try_into().ok()?,
+            modulus: modulus.try_into().ok()?,
+        })
+    }
+}
+
+/// Combines any number of congruences into one. With none at all, every integer qualifies
+pub fn crt(congruences: impl IntoIterator<Item = Congruence>) -> Option<Congruence> {
+    congruences
+        .into_iter()
+        .try_fold(Congruence::new(0, 1), Congruence::combine)
+}
+
+/// Every `x` with `a * x ≡ b` modulo `modulus`, or `None` if there is no such `x`
+pub fn solve_congruence(a: i64, b: i64, modulus: i64) -> Option<Congruence> {
+    if modulus <= 0 {
+        return None;
+    }
+    let m = i128::from(modulus);
+    let (a, b) = (i128::from(a).rem_euclid(m), i128::from(b).rem_euclid(m));
+    let (gcd, inverse, _) = bezout(a, m);
+    if b % gcd != 0 {
+        return None;
+    }
+    let modulus = m / gcd;
+    let residue = (b / gcd * inverse).rem_euclid(modulus);
+    Some(Congruence::new(
+        residue.try_into().ok()?,
+        modulus.try_into().ok()?,
+    ))
+}
+
+/// The non-negative integer solutions `(x, y)` of a linear equation or system
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Solutions {
+    Empty,
+    Unique(u64, u64),
+    /// `first + k * step` for every `k` in `0..count`, or for every `k >= 0` if `count` is `None`.
+    /// Always holds at least two solutions
+    Family {
+        first: (u64, u64),
+        step: (i64, i64),
+        count: Option<u64>,
+    },
+    /// Every pair of non-negative integers
+    All,
+}
+
+impl Solutions {
+    /// The solution with the lowest `x * cost.0 + y * cost.1`. Ties go to the smallest `x`
+    pub fn cheapest(&self, cost: (u64, u64)) -> Option<(u64, u64)> {
+        match *self {
+            Solutions::Empty => None,
+            Solutions::Unique(x, y) => Some((x, y)),
+            Solutions::All => Some((0, 0)),
+            // An endless family can't shrink in either coordinate, so its first member is cheapest
+            Solutions::Family {
+                first, count: None, ..
+            } => Some(first),
+            Solutions::Family {
+                first,
+                step,
+                count: Some(count),
+            } => {
+                let k = i128::from(count - 1);
+                let last = (
+                    (i128::from(first.0) + k * i128::from(step.0)) as u64,
+                    (i128::from(first.1) + k * i128::from(step.1)) as u64,
+                );
+                let price = |(x, y): (u64, u64)| {
+                    u128::from(x) * u128::from(cost.0) + u128::from(y) * u128::from(cost.1)
+                };
+                Some(if price(last) < price(first) {
+                    last
+                } else {
+                    first
+                })
+            }
+        }
+    }
+}
+
+/// The non-negative solutions of `a * x + b * y = c`, worked out in `i128`
+fn solve_equation(a: i128, b: i128, c: i128) -> Option<Solutions> {
+    let point =
+        |x: i128, y: i128| -> Option<(u64, u64)> { Some((x.try_into().ok()?, y.try_into().ok()?)) };
+    let solutions = match (a, b) {
+        (0, 0) if c == 0 => Solutions::All,
+        (0, 0) => Solutions::Empty,
+        (0, b) if c % b != 0 || c / b < 0 => Solutions::Empty,
+        (0, b) => Solutions::Family {
+            first: point(0, c / b)?,
+            step: (1, 0),
+            count: None,
+        },
+        (a, 0) if c % a != 0 || c / a < 0 => Solutions::Empty,
+        (a, 0) => Solutions::Family {
+            first: point(c / a, 0)?,
+            step: (0, 1),
+            count: None,
+        },
+        (a, b) => {
+            let (gcd, x, y) = bezout(a, b);
+            if c % gcd != 0 {
+                return Some(Solutions::Empty);
+            }
+            // Every solution is (x0 + k * step_x, y0 + k * step_y) for some integer k
+            let (x0, y0) = (x * (c / gcd), y * (c / gcd));
+            let (step_x, step_y) = if b > 0 {
+                (b / gcd, -a / gcd)
+            } else {
+                (-b / gcd, a / gcd)
+            };
+            let ceil_div = |n: i128, d: i128| -(-n).div_euclid(d);
+            // x stays non-negative from `low` upwards, and y either from or up to a bound
+            let mut low = ceil_div(-x0, step_x);
+            let mut high = None;
+            if step_y > 0 {
+                low = low.max(ceil_div(-y0, step_y));
+            } else {
+                high = Some(y0.div_euclid(-step_y));
+            }
+            let first = point(x0 + low * step_x, y0 + low * step_y)?;
+            let step = (step_x.try_into().ok()?, step_y.try_into().ok()?);
+            match high {
+                Some(high) if high < low => Solutions::Empty,
+                Some(high) if high == low => Solutions::Unique(first.0, first.1),
+                Some(high) => Solutions::Family {
+                    first,
+                    step,
+                    count: Some((high - low + 1).try_into().ok()?),
+                },
+                None => Solutions::Family {
+                    first,
+                    step,
+                    count: None,
+                },
+            }
+        }
+    };
+    Some(solutions)
+}
+
+/// The non-negative integer solutions of `a * x + b * y = c`. `None` if a solution is too large
+/// for a `u64`
+pub fn solve_linear_equation(a: i64, b: i64, c: i64) -> Option<Solutions> {
+    solve_equation(a.into(), b.into(), c.into())
+}
+
+/// The non-negative integer solutions of two equations `a * x + b * y = c`, each given as
+/// `(a, b, c)`. Equations that are multiples of each other, or `0 = 0`, leave a whole family of
+/// solutions. `None` if a solution is too large for a `u64`
+pub fn solve_linear_system(equations: [(i64, i64, i64); 2]) -> Option<Solutions> {
+    let [(a1, b1, c1), (a2, b2, c2)] =
+        equations.map(|(a, b, c)| (i128::from(a), i128::from(b), i128::from(c)));
+    let determinant = a1 * b2 - a2 * b1;
+    if determinant != 0 {
+        // Cramer's rule, keeping only integer and non-negative answers
+        let (x, y) = (c1 * b2 - c2 * b1, a1 * c2 - a2 * c1);
+        if x % determinant != 0 || y % determinant != 0 {
+            return Some(Solutions::Empty);
+        }
+        let (x, y) = (x / determinant, y / determinant);
+        if x < 0 || y < 0 {
+            return Some(Solutions::Empty);
+        }
+        return Some(Solutions::Unique(x.try_into().ok()?, y.try_into().ok()?));
+    }
+    // The left-hand sides are parallel, so the equations either say the same thing or conflict
+    if a1 == 0 && b1 == 0 {
+        return match c1 {
+            0 => solve_equation(a2, b2, c2),
+            _ => Some(Solutions::Empty),
+        };
+    }
+    if a1 * c2 != a2 * c1 || b1 * c2 != b2 * c1 {
+        return Some(Solutions::Empty);
+    }
+    solve_equation(a1, b1, c1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_gcd_and_inverse() {
+        let bezout = extended_gcd(240, 46).unwrap();
+        assert_eq!(bezout.gcd, 2);
+        assert_eq!(240 * bezout.x + 46 * bezout.y, 2);
+        assert_eq!(extended_gcd(-4, 6).unwrap().gcd, 2);
+        assert_eq!(extended_gcd(i64::MIN, 0), None);
+        assert_eq!(lcm(101, 103), Some(10403));
+        assert_eq!(lcm(i64::MAX, i64::MAX - 1), None);
+
+        assert_eq!(mod_inverse(3, 11), Some(4));
+        assert_eq!(mod_inverse(-3, 11), Some(7));
+        assert_eq!(mod_inverse(6, 9), None);
+    }
+
+    #[test]
+    fn test_chinese_remainders() {
+        let both = crt([Congruence::new(2, 3), Congruence::new(3, 5)]).unwrap();
+        assert_eq!(both, Congruence::new(8, 15));
+        // Moduli sharing a factor can still agree, or not
+        let shared = Congruence::new(3, 4).combine(Congruence::new(5, 6));
+        assert_eq!(shared, Some(Congruence::new(11, 12)));
+        assert_eq!(Congruence::new(0, 4).combine(Congruence::new(1, 6)), None);
+        assert_eq!(crt([]), Some(Congruence::new(0, 1)));
+
+        assert_eq!(solve_congruence(4, 2, 6), Some(Congruence::new(2, 3)));
+        assert_eq!(solve_congruence(4, 1, 6), None);
+        assert!(solve_congruence(5, 10, 5).unwrap().contains(-7));
+    }
+
+    #[test]
+    fn test_linear_systems() {
+        let claw = solve_linear_system([(94, 22, 8400), (34, 67, 5400)]);
+        assert_eq!(claw, Some(Solutions::Unique(80, 40)));
+        let negative = solve_linear_system([(1, 1, 1), (1, -1, 3)]);
+        assert_eq!(negative, Some(Solutions::Empty));
+
+        let offset = 10_000_000_000_000;
+        let big = solve_linear_system([(26, 67, 12748 + offset), (66, 21, 12176 + offset)]);
+        assert_eq!(big, Some(Solutions::Unique(118679050709, 103199174542)));
+
+        // The second equation is just twice the first
+        let collinear = solve_linear_system([(1, 1, 5), (2, 2, 10)]).unwrap();
+        assert_eq!(collinear, solve_linear_equation(1, 1, 5).unwrap());
+        let family = solve_linear_system([(2, 2, 10), (4, 4, 20)]).unwrap();
+        assert_eq!(
+            family,
+            Solutions::Family {
+                first: (0, 5),
+                step: (1, -1),
+                count: Some(6),
+            }
+        );
+        assert_eq!(family.cheapest((3, 1)), Some((0, 5)));
+        assert_eq!(family.cheapest((1, 3)), Some((5, 0)));
+        assert_eq!(
+            solve_linear_system([(1, 1, 5), (2, 2, 11)]),
+            Some(Solutions::Empty)
+        );
+
+        assert_eq!(
+            solve_linear_system([(0, 0, 0), (0, 3, 6)]),
+            Some(Solutions::Family {
+                first: (0, 2),
+                step: (1, 0),
+                count: None,
+            })
+        );
+        assert_eq!(
+            solve_linear_system([(0, 0, 0), (0, 0, 0)]),
+            Some(Solutions::All)
+        );
+        assert_eq!(
+            solve_linear_equation(2, -3, 1),
+            Some(Solutions::Family {
+                first: (2, 1),
+                step: (3, 2),
+                count: None,
+            })
+        );
+        assert_eq!(solve_linear_equation(4, 6, 3), Some(Solutions::Empty));
+    }
+}
@@ -0,0 +1,132 @@
+use std::{collections::BTreeMap, fmt};
+
+/// Puzzle parameters that can be set from the command line, as `--<name> <value>`
+pub const PARAMETERS: [&str; 7] = [
+    "width",
+    "height",
+    "seconds",
+    "bytes",
+    "blinks",
+    "threshold",
+    "budget",
+];
+
+/// Parameter values given on the command line, before a day turns them into its own parameters
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Settings {
+    values: BTreeMap<String, u64>,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum ParamError {
+    /// The day has no parameter with this name
+    Unsupported(String),
+    Invalid {
+        name: String,
+        reason: String,
+    },
+}
+
+impl fmt::Display for ParamError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ParamError::Unsupported(name) => write!(f, "--{name} does not apply to this day"),
+            ParamError::Invalid { name, reason } => write!(f, "Invalid --{name}: {reason}"),
+        }
+    }
+}
+
+impl std::error::Error for ParamError {}
+
+impl Settings {
+    pub fn set(&mut self, name: &str, value: u64) {
+        self.values.insert(name.to_string(), value);
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&str, u64)> {
+        self.values
+            .iter()
+            .map(|(name, &value)| (name.as_str(), value))
+    }
+
+    /// Fails on the first setting that isn't one of `names`
+    pub fn only(&self, names: &[&str]) -> Result<(), ParamError> {
+        match self
+            .values
+            .keys()
+            .find(|name| !names.contains(&name.as_str()))
+        {
+            Some(name) => Err(ParamError::Unsupported(name.clone())),
+            None => Ok(()),
+        }
+    }
+
+    /// The value of a setting converted to the type the day uses, or `default` if it isn't set
+    pub fn get_or<T: TryFrom<u64>>(&self, name: &str, default: T) -> Result<T, ParamError> {
+        match self.values.get(name) {
+            Some(&value) => T::try_from(value).map_err(|_| ParamError::Invalid {
+                name: name.to_string(),
+                reason: format!("{value} is too large"),
+            }),
+            None => Ok(default),
+        }
+    }
+}
+
+/// A day's typed parameters, built from its defaults and any settings that override them
+pub trait Parameters: Default {
+    fn configure(settings: &Settings) -> Result<Self, ParamError>;
+}
+
+/// Days without parameters accept no settings at all
+impl Parameters for () {
+    fn configure(settings: &Settings) -> Result<(), ParamError> {
+        settings.only(&[])
+    }
+}
+
+/// Checks that a size parameter leaves room for at least one tile
+pub fn positive<T: Default + PartialOrd>(name: &str, value: T) -> Result<T, ParamError> {
+    if value > T::default() {
+        Ok(value)
+    } else {
+        Err(ParamError::Invalid {
+            name: name.to_string(),
+            reason: String::from("must be at least 1"),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_settings() {
+        let mut settings = Settings::default();
+        assert_eq!(<()>::configure(&settings), Ok(()));
+        settings.set("width", 11);
+        assert_eq!(settings.get_or("width", 101i32), Ok(11));
+        assert_eq!(settings.get_or("height", 103i32), Ok(103));
+        assert_eq!(settings.only(&["width", "height"]), Ok(()));
+        assert_eq!(
+            settings.only(&["blinks"]),
+            Err(ParamError::Unsupported(String::from("width")))
+        );
+        assert_eq!(
+            <()>::configure(&settings),
+            Err(ParamError::Unsupported(String::from("width")))
+        );
+
+        settings.set("width", u64::MAX);
+        assert!(matches!(
+            settings.get_or("width", 101i32),
+            Err(ParamError::Invalid { .. })
+        ));
+        assert!(positive("width", 0usize).is_err());
+    }
+}
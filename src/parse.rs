@@ -0,0 +1,486 @@
+use std::{fmt, str::FromStr};
+
+use crate::grid::Grid;
+
+/// Why a piece of input could not be parsed
+#[derive(Clone, Debug, PartialEq)]
+pub enum ErrorKind {
+    /// Something was missing, such as a separator or a whole section
+    Expected(String),
+    Unexpected {
+        found: char,
+        expected: &'static str,
+    },
+    /// Not an integer, or too large for the type it is read into
+    InvalidInteger(String),
+    /// A word that isn't one of the keywords allowed here
+    Unknown {
+        what: &'static str,
+        text: String,
+    },
+    /// A grid row with a different number of cells from the first row
+    Ragged {
+        expected: usize,
+        found: usize,
+    },
+}
+
+/// A parse failure, pointing at the line and column where it happened. Both count from 1, and
+/// columns count characters rather than bytes
+#[derive(Clone, Debug, PartialEq)]
+pub struct ParseError {
+    pub line: usize,
+    pub column: usize,
+    pub kind: ErrorKind,
+}
+
+impl fmt::Display for ErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ErrorKind::Expected(what) => write!(f, "expected {what}"),
+            ErrorKind::Unexpected { found, expected } => {
+                write!(f, "unexpected {found:?}, expected {expected}")
+            }
+            ErrorKind::InvalidInteger(text) => write!(f, "{text:?} is not a valid integer"),
+            ErrorKind::Unknown { what, text } => write!(f, "unknown {what} {text:?}"),
+            ErrorKind::Ragged { expected, found } => write!(
+                f,
+                "row has {found} cells where the rows above have {expected}"
+            ),
+        }
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "line {}, column {}: {}",
+            self.line, self.column, self.kind
+        )
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+pub type ParseResult<T> = Result<T, ParseError>;
+
+/// A slice of the input that knows where it starts, so that errors found in it can point back at
+/// the exact character
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Span<'a> {
+    pub text: &'a str,
+    pub line: usize,
+    pub column: usize,
+}
+
+impl<'a> Span<'a> {
+    /// An error at the start of the span
+    pub fn error(&self, kind: ErrorKind) -> ParseError {
+        self.error_at(0, kind)
+    }
+
+    /// An error `offset` bytes into the span
+    pub fn error_at(&self, offset: usize, kind: ErrorKind) -> ParseError {
+        ParseError {
+            line: self.line,
+            column: self.column + self.text[..offset].chars().count(),
+            kind,
+        }
+    }
+
+    /// The part of the span from `start` to `end` bytes into it
+    fn slice(&self, start: usize, end: usize) -> Span<'a> {
+        Span {
+            text: &self.text[start..end],
+            line: self.line,
+            column: self.column + self.text[..start].chars().count(),
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.text.is_empty()
+    }
+
+    pub fn trim(&self) -> Span<'a> {
+        let start = self.text.len() - self.text.trim_start().len();
+        self.slice(start, start + self.text.trim().len())
+    }
+
+    /// The pieces between each `separator`, each trimmed of surrounding whitespace
+    pub fn split(self, separator: &'a str) -> impl Iterator<Item = Span<'a>> {
+        let mut start = 0;
+        self.text.split(separator).map(move |piece| {
+            let span = self.slice(start, start + piece.len());
+            start += piece.len() + separator.len();
+            span.trim()
+        })
+    }
+
+    /// The runs of non-whitespace characters
+    pub fn words(self) -> impl Iterator<Item = Span<'a>> {
+        self.text.split_whitespace().map(move |word| {
+            let start = word.as_ptr() as usize - self.text.as_ptr() as usize;
+            self.slice(start, start + word.len())
+        })
+    }
+
+    /// Splits around the first `separator`, trimming both sides. Lines such as `Register A: 729`
+    /// or `x00: 1` split into a key and a value this way
+    pub fn key_value(&self, separator: &str) -> ParseResult<(Span<'a>, Span<'a>)> {
+        let Some(start) = self.text.find(separator) else {
+            return Err(self.error_at(
+                self.text.len(),
+                ErrorKind::Expected(format!("{separator:?}")),
+            ));
+        };
+        let key = self.slice(0, start).trim();
+        let value = self.slice(start + separator.len(), self.text.len()).trim();
+        Ok((key, value))
+    }
+
+    /// The whole span, trimmed, read as an integer
+    pub fn integer<T: FromStr>(&self) -> ParseResult<T> {
+        let span = self.trim();
+        if span.is_empty() {
+            return Err(span.error(ErrorKind::Expected(String::from("a number"))));
+        }
+        span.text
+            .parse()
+            .map_err(|_| span.error(ErrorKind::InvalidInteger(span.text.to_string())))
+    }
+
+    /// Integers separated by whitespace
+    pub fn integers<T: FromStr>(self) -> ParseResult<Vec<T>> {
+        self.words().map(|word| word.integer()).collect()
+    }
+
+    /// Integers separated by `separator`, such as the `,` in `75,47,61`
+    pub fn integer_list<T: FromStr>(self, separator: &'a str) -> ParseResult<Vec<T>> {
+        self.split(separator).map(|piece| piece.integer()).collect()
+    }
+
+    /// Every integer written anywhere in the span, ignoring the text around them, so that
+    /// `Button A: X+94, Y+34` gives 94 and 34. A `-` directly before the digits makes the number
+    /// negative
+    pub fn numbers<T: FromStr>(self) -> ParseResult<Vec<T>> {
+        let bytes = self.text.as_bytes();
+        let mut numbers = Vec::new();
+        let mut i = 0;
+        while i < bytes.len() {
+            if !bytes[i].is_ascii_digit() {
+                i += 1;
+                continue;
+            }
+            let start = if i > 0 && bytes[i - 1] == b'-' {
+                i - 1
+            } else {
+                i
+            };
+            while i < bytes.len() && bytes[i].is_ascii_digit() {
+                i += 1;
+            }
+            numbers.push(self.slice(start, i).integer()?);
+        }
+        Ok(numbers)
+    }
+
+    /// Exactly `N` numbers, as found by [`Span::numbers`]
+    pub fn exact_numbers<T: FromStr, const N: usize>(self) -> ParseResult<[T; N]> {
+        let numbers = self.numbers()?;
+        let found = numbers.len();
+        numbers.try_into().map_err(|_| {
+            let expected = format!("{N} numbers, not {found}");
+            self.error(ErrorKind::Expected(expected))
+        })
+    }
+
+    /// Converts every character with `cell`, pointing at the first one it rejects
+    pub fn cells<T, F>(&self, expected: &'static str, mut cell: F) -> ParseResult<Vec<T>>
+    where
+        F: FnMut(char) -> Option<T>,
+    {
+        self.text
+            .char_indices()
+            .map(|(offset, c)| {
+                cell(c).ok_or_else(|| {
+                    self.error_at(offset, ErrorKind::Unexpected { found: c, expected })
+                })
+            })
+            .collect()
+    }
+
+    /// Matches the trimmed span against a set of keywords
+    pub fn keyword<T: Copy>(&self, what: &'static str, keywords: &[(&str, T)]) -> ParseResult<T> {
+        let span = self.trim();
+        keywords
+            .iter()
+            .find(|(keyword, _)| *keyword == span.text)
+            .map(|&(_, value)| value)
+            .ok_or_else(|| {
+                span.error(ErrorKind::Unknown {
+                    what,
+                    text: span.text.to_string(),
+                })
+            })
+    }
+}
+
+/// Every line of the input, numbered from 1, without line endings
+pub fn lines(input: &str) -> impl Iterator<Item = Span<'_>> {
+    input.lines().enumerate().map(|(i, text)| Span {
+        text,
+        line: i + 1,
+        column: 1,
+    })
+}
+
+/// The lines of the input that hold more than whitespace
+pub fn non_blank_lines(input: &str) -> impl Iterator<Item = Span<'_>> {
+    lines(input).filter(|line| !line.text.trim().is_empty())
+}
+
+/// Groups of non-blank lines separated by one or more blank lines
+pub fn sections(input: &str) -> Vec<Vec<Span<'_>>> {
+    let mut sections = vec![Vec::new()];
+    for line in lines(input) {
+        if !line.text.trim().is_empty() {
+            sections.last_mut().unwrap().push(line);
+        } else if !sections.last().unwrap().is_empty() {
+            sections.push(Vec::new());
+        }
+    }
+    sections.retain(|section| !section.is_empty());
+    sections
+}
+
+/// Exactly `N` sections, with `what` describing them for the error when there are more or fewer.
+/// A missing section is reported just past the end of the input, an extra one at its first line
+pub fn sections_of<'a, const N: usize>(
+    input: &'a str,
+    what: &'static str,
+) -> ParseResult<[Vec<Span<'a>>; N]> {
+    let sections = sections(input);
+    let found = sections.len();
+    let line = match sections.get(N) {
+        Some(extra) => extra[0].line,
+        None => lines(input).count() + 1,
+    };
+    sections.try_into().map_err(|_| ParseError {
+        line,
+        column: 1,
+        kind: ErrorKind::Expected(format!(
+            "{N} sections separated by blank lines ({what}), not {found}"
+        )),
+    })
+}
+
+/// A grid with one character per cell, one row per line. Lines are trimmed, and `cell` converts
+/// each character, with `expected` describing the characters it accepts
+pub fn grid<'a, T, F>(
+    lines: impl IntoIterator<Item = Span<'a>>,
+    expected: &'static str,
+    mut cell: F,
+) -> ParseResult<Grid<T>>
+where
+    F: FnMut(char) -> Option<T>,
+{
+    let mut rows: Vec<Vec<T>> = Vec::new();
+    let mut first_line = None;
+    for line in lines {
+        let line = line.trim();
+        let row = line.cells(expected, &mut cell)?;
+        if let Some(width) = rows.first().map(Vec::len) {
+            if row.len() != width {
+                // Point at the first extra cell, or the end of a short row
+                let offset = line
+                    .text
+                    .char_indices()
+                    .nth(width)
+                    .map_or(line.text.len(), |(offset, _)| offset);
+                return Err(line.error_at(
+                    offset,
+                    ErrorKind::Ragged {
+                        expected: width,
+                        found: row.len(),
+                    },
+                ));
+            }
+        }
+        first_line.get_or_insert(line);
+        rows.push(row);
+    }
+    match first_line {
+        Some(line) if line.is_empty() => {
+            Err(line.error(ErrorKind::Expected(String::from("a grid"))))
+        }
+        Some(_) => Ok(Grid::from_rows(rows)),
+        None => Err(ParseError {
+            line: 1,
+            column: 1,
+            kind: ErrorKind::Expected(String::from("a grid")),
+        }),
+    }
+}
+
+/// A grid of any characters
+pub fn char_grid<'a>(lines: impl IntoIterator<Item = Span<'a>>) -> ParseResult<Grid<char>> {
+    grid(lines, "any character", Some)
+}
+
+/// A grid of any characters that holds exactly one of each of the `markers`, such as a start and
+/// an end
+pub fn marked_char_grid<'a>(
+    lines: impl IntoIterator<Item = Span<'a>>,
+    markers: &[char],
+) -> ParseResult<Grid<char>> {
+    let lines: Vec<Span> = lines.into_iter().map(|line| line.trim()).collect();
+    let grid = char_grid(lines.iter().copied())?;
+    for &marker in markers {
+        let mut found = lines.iter().flat_map(|line| {
+            line.text
+                .char_indices()
+                .filter(move |&(_, c)| c == marker)
+                .map(move |(offset, _)| (line, offset))
+        });
+        if found.next().is_none() {
+            let last = lines.last().expect("a parsed grid has lines");
+            return Err(last.error_at(
+                last.text.len(),
+                ErrorKind::Expected(format!("a {marker:?} in the grid")),
+            ));
+        }
+        if let Some((line, offset)) = found.next() {
+            return Err(line.error_at(offset, ErrorKind::Expected(format!("only one {marker:?}"))));
+        }
+    }
+    Ok(grid)
+}
+
+/// Unwraps parsed input for the parts of a day, which can only report a malformed input by
+/// panicking. The command line checks input with `Day::parse` before solving, so this only fires
+/// when a part is called directly
+pub fn expect_valid<T>(parsed: ParseResult<T>) -> T {
+    parsed.unwrap_or_else(|error| panic!("Malformed input: {error}"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn span(text: &str) -> Span<'_> {
+        Span {
+            text,
+            line: 3,
+            column: 1,
+        }
+    }
+
+    #[test]
+    fn test_integers_point_at_the_bad_one() {
+        assert_eq!(span("3   4").integers::<u32>(), Ok(vec![3, 4]));
+        assert_eq!(
+            span("75,47,61").integer_list::<u8>(","),
+            Ok(vec![75, 47, 61])
+        );
+        let error = span("75, 4x7,61").integer_list::<u8>(",").unwrap_err();
+        assert_eq!(
+            error,
+            ParseError {
+                line: 3,
+                column: 5,
+                kind: ErrorKind::InvalidInteger(String::from("4x7"))
+            }
+        );
+        assert_eq!(
+            error.to_string(),
+            "line 3, column 5: \"4x7\" is not a valid integer"
+        );
+        assert!(span("1 300").integers::<u8>().is_err());
+    }
+
+    #[test]
+    fn test_numbers_in_text() {
+        assert_eq!(span("p=0,4 v=3,-3").numbers::<i32>(), Ok(vec![0, 4, 3, -3]));
+        assert_eq!(
+            span("Button A: X+94, Y+34").exact_numbers::<u64, 2>(),
+            Ok([94, 34])
+        );
+        assert_eq!(
+            span("Register A: 1 2").exact_numbers::<u64, 1>(),
+            Err(ParseError {
+                line: 3,
+                column: 1,
+                kind: ErrorKind::Expected(String::from("1 numbers, not 2"))
+            })
+        );
+    }
+
+    #[test]
+    fn test_key_value_and_keywords() {
+        let (key, value) = span("Register A: 729").key_value(":").unwrap();
+        assert_eq!((key.text, value.text), ("Register A", "729"));
+        assert_eq!(value.column, 13);
+        assert_eq!(
+            span("x00 1").key_value(":").unwrap_err().kind,
+            ErrorKind::Expected(String::from("\":\""))
+        );
+
+        let gate = span("x00 NAND y00").words().nth(1).unwrap();
+        let error = gate
+            .keyword("operation", &[("AND", 0), ("OR", 1)])
+            .unwrap_err();
+        assert_eq!(error.column, 5);
+        assert_eq!(
+            error.to_string(),
+            "line 3, column 5: unknown operation \"NAND\""
+        );
+    }
+
+    #[test]
+    fn test_sections_and_grids() {
+        let input = "#.\n.#\n\n\n<>^v\n<x\n";
+        let parts = sections(input);
+        assert_eq!(parts.len(), 2);
+        assert_eq!(parts[1][0].line, 5);
+
+        let grid = char_grid(parts[0].clone()).unwrap();
+        assert_eq!(grid.to_string(), "#.\n.#\n");
+        let moves = parts[1][1].cells("an arrow", |c| "<>^v".find(c));
+        assert_eq!(
+            moves.unwrap_err().to_string(),
+            "line 6, column 2: unexpected 'x', expected an arrow"
+        );
+
+        let error = sections_of::<3>(input, "a, b and c").unwrap_err();
+        assert_eq!((error.line, error.column), (7, 1));
+        assert_eq!(
+            error.kind,
+            ErrorKind::Expected(String::from(
+                "3 sections separated by blank lines (a, b and c), not 2"
+            ))
+        );
+        assert_eq!(sections_of::<1>(input, "a").unwrap_err().line, 5);
+
+        let ragged = char_grid(non_blank_lines("###\n#.\n")).unwrap_err();
+        assert_eq!((ragged.line, ragged.column), (2, 3));
+        assert_eq!(
+            char_grid(non_blank_lines("\n")).unwrap_err().kind,
+            ErrorKind::Expected(String::from("a grid"))
+        );
+    }
+
+    #[test]
+    fn test_marked_grids() {
+        let grid = marked_char_grid(non_blank_lines("S.\n.E\n"), &['S', 'E']).unwrap();
+        assert_eq!(grid.find(&'E'), Some((1, 1)));
+
+        let missing = marked_char_grid(non_blank_lines("S.\n..\n"), &['S', 'E']).unwrap_err();
+        assert_eq!(
+            missing.to_string(),
+            "line 2, column 3: expected a 'E' in the grid"
+        );
+        let twice = marked_char_grid(non_blank_lines("S.\n.S\n"), &['S']).unwrap_err();
+        assert_eq!(twice.to_string(), "line 2, column 2: expected only one 'S'");
+    }
+}
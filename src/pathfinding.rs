@@ -0,0 +1,343 @@
+use std::{
+    cmp::Reverse,
+    collections::{BinaryHeap, HashMap, HashSet, VecDeque},
+    hash::Hash,
+};
+
+use crate::grid::{Grid, Position};
+
+/// Everything a complete Dijkstra search learned: the cheapest cost to reach each state, and every
+/// state that reaches it at that cost. Together the predecessors form a DAG of all optimal paths
+#[derive(Clone, Debug)]
+pub struct Search<S> {
+    distances: HashMap<S, u64>,
+    predecessors: HashMap<S, Vec<S>>,
+}
+
+impl<S: Clone + Eq + Hash> Search<S> {
+    /// The cheapest cost from a start to `state`, if it can be reached at all
+    pub fn distance(&self, state: &S) -> Option<u64> {
+        self.distances.get(state).copied()
+    }
+
+    /// Every state reached by the search with its cheapest cost, in no particular order
+    pub fn distances(&self) -> impl Iterator<Item = (&S, u64)> {
+        self.distances.iter().map(|(state, &cost)| (state, cost))
+    }
+
+    /// The states that reach `state` on an optimal path. Empty for starts and unreached states
+    pub fn predecessors(&self, state: &S) -> &[S] {
+        self.predecessors.get(state).map_or(&[], Vec::as_slice)
+    }
+
+    /// One optimal path from a start to `goal`, including both ends
+    pub fn path_to(&self, goal: &S) -> Option<Vec<S>> {
+        self.distance(goal)?;
+        let mut path = vec![goal.clone()];
+        while let Some(previous) = self.predecessors(path.last().unwrap()).first() {
+            path.push(previous.clone());
+        }
+        path.reverse();
+        Some(path)
+    }
+
+    /// The cheapest of `goals`, with its cost. Ties go to the first goal given
+    pub fn nearest<'a>(&self, goals: impl IntoIterator<Item = &'a S>) -> Option<(&'a S, u64)>
+    where
+        S: 'a,
+    {
+        goals
+            .into_iter()
+            .filter_map(|goal| Some((goal, self.distance(goal)?)))
+            .min_by_key(|&(_, cost)| cost)
+    }
+
+    /// Every state on any optimal path to the cheapest of `goals`. When several goals share the
+    /// cheapest cost, the paths to all of them are included
+    pub fn on_best_paths(&self, goals: &[S]) -> HashSet<S> {
+        let mut states = HashSet::new();
+        let Some((_, best)) = self.nearest(goals) else {
+            return states;
+        };
+        let mut stack = goals
+            .iter()
+            .filter(|goal| self.distance(goal) == Some(best))
+            .cloned()
+            .collect::<Vec<S>>();
+        while let Some(state) = stack.pop() {
+            if states.insert(state.clone()) {
+                stack.extend(self.predecessors(&state).iter().cloned());
+            }
+        }
+        states
+    }
+}
+
+/// Runs Dijkstra's algorithm from every start to every reachable state. `successors` gives the
+/// states one move away from a state, with the cost of that move
+pub fn dijkstra<S, F, I>(starts: impl IntoIterator<Item = S>, mut successors: F) -> Search<S>
+where
+    S: Clone + Eq + Hash + Ord,
+    F: FnMut(&S) -> I,
+    I: IntoIterator<Item = (S, u64)>,
+{
+    let mut distances = HashMap::new();
+    let mut predecessors: HashMap<S, Vec<S>> = HashMap::new();
+    let mut queue = BinaryHeap::new();
+    for start in starts {
+        distances.insert(start.clone(), 0);
+        queue.push(Reverse((0, start)));
+    }
+
+    while let Some(Reverse((cost, state))) = queue.pop() {
+        if distances.get(&state).is_some_and(|&best| cost > best) {
+            continue;
+        }
+        for (next, step) in successors(&state) {
+            let next_cost = cost + step;
+            match distances.get(&next) {
+                Some(&best) if next_cost > best => {}
+                Some(&best) if next_cost == best => {
+                    predecessors.entry(next).or_default().push(state.clone());
+                }
+                _ => {
+                    distances.insert(next.clone(), next_cost);
+                    predecessors.insert(next.clone(), vec![state.clone()]);
+                    queue.push(Reverse((next_cost, next)));
+                }
+            }
+        }
+    }
+    Search {
+        distances,
+        predecessors,
+    }
+}
+
+/// Where a breadth-first search keeps the parent of each state it reaches
+pub trait Parents<S> {
+    fn parent(&self, state: &S) -> Option<&S>;
+    fn set_parent(&mut self, state: S, parent: S);
+}
+
+impl<S: Eq + Hash> Parents<S> for HashMap<S, S> {
+    fn parent(&self, state: &S) -> Option<&S> {
+        self.get(state)
+    }
+
+    fn set_parent(&mut self, state: S, parent: S) {
+        self.insert(state, parent);
+    }
+}
+
+/// A grid of positions keeps a parent per cell, which is much faster than hashing on large grids
+impl Parents<Position> for Grid<Option<Position>> {
+    fn parent(&self, position: &Position) -> Option<&Position> {
+        self[*position].as_ref()
+    }
+
+    fn set_parent(&mut self, position: Position, parent: Position) {
+        self[position] = Some(parent);
+    }
+}
+
+/// What a breadth-first search learned: the state each reached state was first entered from. Only
+/// that one parent is kept, so memory stays at one entry per state however long the paths get,
+/// and a path is only rebuilt when it is asked for
+#[derive(Clone, Debug)]
+pub struct Tree<S, P = HashMap<S, S>> {
+    start: S,
+    parents: P,
+}
+
+impl<S: Clone + PartialEq, P: Parents<S>> Tree<S, P> {
+    pub fn reached(&self, state: &S) -> bool {
+        *state == self.start || self.parents.parent(state).is_some()
+    }
+
+    /// The states walked through on the way back from `goal` to the start, excluding the start
+    fn walk_back<'a>(&'a self, goal: &'a S) -> impl Iterator<Item = &'a S> + 'a {
+        std::iter::successors(Some(goal), |&state| self.parents.parent(state))
+            .take_while(|&state| *state != self.start)
+    }
+
+    /// How many moves a shortest path to `goal` takes, if it was reached
+    pub fn steps_to(&self, goal: &S) -> Option<usize> {
+        self.reached(goal).then(|| self.walk_back(goal).count())
+    }
+
+    /// A shortest path from the start to `goal`, including both ends
+    pub fn path_to(&self, goal: &S) -> Option<Vec<S>> {
+        if !self.reached(goal) {
+            return None;
+        }
+        let mut path = self.walk_back(goal).cloned().collect::<Vec<S>>();
+        path.push(self.start.clone());
+        path.reverse();
+        Some(path)
+    }
+}
+
+/// Searches outwards from `start` a move at a time, where every move costs the same, stopping
+/// early once `is_goal` holds for a reached state. `successors` gives the states one move away
+pub fn bfs<S, F, I, G>(start: S, successors: F, is_goal: G) -> Tree<S>
+where
+    S: Clone + Eq + Hash,
+    F: FnMut(&S) -> I,
+    I: IntoIterator<Item = S>,
+    G: FnMut(&S) -> bool,
+{
+    bfs_in(HashMap::new(), start, successors, is_goal)
+}
+
+/// Like [`bfs`], but keeping parents in `parents`, which should start out empty
+pub fn bfs_in<S, P, F, I, G>(
+    mut parents: P,
+    start: S,
+    mut successors: F,
+    mut is_goal: G,
+) -> Tree<S, P>
+where
+    S: Clone + PartialEq,
+    P: Parents<S>,
+    F: FnMut(&S) -> I,
+    I: IntoIterator<Item = S>,
+    G: FnMut(&S) -> bool,
+{
+    let mut queue = VecDeque::from([start.clone()]);
+    while let Some(state) = queue.pop_front() {
+        if is_goal(&state) {
+            break;
+        }
+        for next in successors(&state) {
+            if next == start || parents.parent(&next).is_some() {
+                continue;
+            }
+            parents.set_parent(next.clone(), state.clone());
+            queue.push_back(next);
+        }
+    }
+    Tree { start, parents }
+}
+
+/// Finds one cheapest path from `start` to a state satisfying `is_goal`, returning it with its
+/// cost. `heuristic` must never overestimate the remaining cost, or the path may not be optimal
+pub fn astar<S, F, I, H, G>(
+    start: S,
+    mut successors: F,
+    mut heuristic: H,
+    mut is_goal: G,
+) -> Option<(Vec<S>, u64)>
+where
+    S: Clone + Eq + Hash + Ord,
+    F: FnMut(&S) -> I,
+    I: IntoIterator<Item = (S, u64)>,
+    H: FnMut(&S) -> u64,
+    G: FnMut(&S) -> bool,
+{
+    let mut distances = HashMap::from([(start.clone(), 0)]);
+    let mut parents: HashMap<S, S> = HashMap::new();
+    let mut queue = BinaryHeap::from([Reverse((heuristic(&start), 0, start))]);
+
+    while let Some(Reverse((_, cost, state))) = queue.pop() {
+        if distances.get(&state).is_some_and(|&best| cost > best) {
+            continue;
+        }
+        if is_goal(&state) {
+            let mut path = vec![state];
+            while let Some(parent) = parents.get(path.last().unwrap()) {
+                path.push(parent.clone());
+            }
+            path.reverse();
+            return Some((path, cost));
+        }
+        for (next, step) in successors(&state) {
+            let next_cost = cost + step;
+            if distances.get(&next).is_some_and(|&best| next_cost >= best) {
+                continue;
+            }
+            distances.insert(next.clone(), next_cost);
+            parents.insert(next.clone(), state.clone());
+            queue.push(Reverse((next_cost + heuristic(&next), next_cost, next)));
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // a -> b -> d and a -> c -> d both cost 3, a -> d directly costs 5
+    fn edges(node: &char) -> Vec<(char, u64)> {
+        match node {
+            'a' => vec![('b', 1), ('c', 2), ('d', 5)],
+            'b' => vec![('d', 2)],
+            'c' => vec![('d', 1)],
+            'd' => vec![('e', 1)],
+            _ => vec![],
+        }
+    }
+
+    #[test]
+    fn test_dijkstra_keeps_every_best_predecessor() {
+        let search = dijkstra(['a'], edges);
+        assert_eq!(search.distance(&'d'), Some(3));
+        assert_eq!(search.distance(&'z'), None);
+        assert_eq!(search.predecessors(&'d').len(), 2);
+        assert!(search.predecessors(&'a').is_empty());
+
+        let path = search.path_to(&'e').unwrap();
+        assert_eq!(path.first(), Some(&'a'));
+        assert_eq!(path.last(), Some(&'e'));
+        assert_eq!(path.len(), 4);
+
+        let mut on_paths = search.on_best_paths(&['e']).into_iter().collect::<Vec<_>>();
+        on_paths.sort();
+        assert_eq!(on_paths, vec!['a', 'b', 'c', 'd', 'e']);
+        assert_eq!(search.nearest(&['e', 'c']), Some((&'c', 2)));
+    }
+
+    #[test]
+    fn test_bfs_rebuilds_paths() {
+        let tree = bfs(
+            'a',
+            |&node| edges(&node).into_iter().map(|(next, _)| next),
+            |_| false,
+        );
+        // Fewest moves, whatever the costs
+        assert_eq!(tree.path_to(&'d'), Some(vec!['a', 'd']));
+        assert_eq!(tree.steps_to(&'e'), Some(2));
+        assert_eq!(tree.steps_to(&'a'), Some(0));
+        assert_eq!(tree.path_to(&'z'), None);
+
+        let early = bfs(
+            'a',
+            |&node| edges(&node).into_iter().map(|(next, _)| next),
+            |&n| n == 'a',
+        );
+        assert!(early.reached(&'a') && !early.reached(&'b'));
+    }
+
+    #[test]
+    fn test_astar_on_open_grid() {
+        let goal = (4i32, 3i32);
+        let (path, cost) = astar(
+            (0i32, 0i32),
+            |&(x, y)| {
+                [(1, 0), (0, 1), (-1, 0), (0, -1)]
+                    .into_iter()
+                    .map(move |(dx, dy)| ((x + dx, y + dy), 1))
+                    .filter(|&((x, y), _)| (0..5).contains(&x) && (0..5).contains(&y))
+            },
+            |&(x, y)| (goal.0.abs_diff(x) + goal.1.abs_diff(y)) as u64,
+            |&state| state == goal,
+        )
+        .unwrap();
+        assert_eq!(cost, 7);
+        assert_eq!(path.len(), 8);
+        assert_eq!(path.last(), Some(&goal));
+
+        assert_eq!(astar('a', edges, |_| 0, |&node| node == 'z'), None);
+    }
+}
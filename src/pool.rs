@@ -0,0 +1,139 @@
+use std::{
+    any::Any,
+    num::NonZeroUsize,
+    panic,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Mutex,
+    },
+    thread,
+    time::{Duration, Instant},
+};
+
+use crate::{
+    answer::{Answer, SolveError},
+    Day, Part,
+};
+
+/// One part of one day to be solved against the given input
+pub struct Job<'a> {
+    pub day: &'a Day,
+    pub part: Part,
+    pub input: &'a str,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum Outcome {
+    Solved(Answer),
+    /// The solver panicked, with the panic message where there was one
+    Panicked(String),
+    /// The solver finished without an answer, such as by running out of budget
+    Failed(SolveError),
+}
+
+#[derive(Clone, Debug)]
+pub struct Run {
+    pub day: u8,
+    pub part: Part,
+    pub outcome: Outcome,
+    pub elapsed: Duration,
+}
+
+fn panic_message(payload: Box<dyn Any + Send>) -> String {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        message.to_string()
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message.clone()
+    } else {
+        String::from("unknown panic")
+    }
+}
+
+/// Solves a single job, catching a panic in the solver rather than letting it unwind further
+pub fn solve_isolated(job: &Job) -> Run {
+    let start = Instant::now();
+    let result = panic::catch_unwind(|| job.day.solve(job.input, job.part));
+    let elapsed = start.elapsed();
+    Run {
+        day: job.day.number,
+        part: job.part,
+        outcome: match result {
+            Ok(Ok(answer)) => Outcome::Solved(answer),
+            Ok(Err(error)) => Outcome::Failed(error),
+            Err(payload) => Outcome::Panicked(panic_message(payload)),
+        },
+        elapsed,
+    }
+}
+
+pub fn default_workers() -> usize {
+    thread::available_parallelism().map_or(1, NonZeroUsize::get)
+}
+
+/// Solves every job on a pool of `workers` threads, each taking the next unclaimed job until none
+/// are left. The runs come back in the same order as the jobs
+pub fn run_jobs(jobs: &[Job], workers: usize) -> Vec<Run> {
+    let next = AtomicUsize::new(0);
+    let runs = Mutex::new(Vec::with_capacity(jobs.len()));
+    thread::scope(|scope| {
+        for _ in 0..workers.clamp(1, jobs.len().max(1)) {
+            scope.spawn(|| loop {
+                let index = next.fetch_add(1, Ordering::Relaxed);
+                let Some(job) = jobs.get(index) else {
+                    break;
+                };
+                let run = solve_isolated(job);
+                runs.lock().unwrap().push((index, run));
+            });
+        }
+    });
+    let mut runs = runs.into_inner().unwrap();
+    runs.sort_by_key(|(index, _)| *index);
+    runs.into_iter().map(|(_, run)| run).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::get_day;
+
+    #[test]
+    fn test_runs_keep_job_order() {
+        let input = "3   4\n4   3\n2   5\n1   3\n3   9\n3   3\n";
+        let day = get_day(1).unwrap();
+        let jobs = [Part::One, Part::Two, Part::One]
+            .map(|part| Job { day, part, input })
+            .into_iter()
+            .collect::<Vec<_>>();
+        let runs = run_jobs(&jobs, 2);
+        let outcomes = runs
+            .iter()
+            .map(|run| run.outcome.clone())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            outcomes,
+            [11u32, 31, 11].map(|n| Outcome::Solved(Answer::from(n)))
+        );
+        assert!(run_jobs(&[], 4).is_empty());
+    }
+
+    #[test]
+    fn test_panics_are_isolated() {
+        // Day 9 expects a disk map made only of digits
+        let jobs = [
+            Job {
+                day: get_day(9).unwrap(),
+                part: Part::One,
+                input: "12x45\n",
+            },
+            Job {
+                day: get_day(1).unwrap(),
+                part: Part::One,
+                input: "3   4\n",
+            },
+        ];
+        let runs = run_jobs(&jobs, 2);
+        assert!(matches!(runs[0].outcome, Outcome::Panicked(_)));
+        assert_eq!(runs[1].outcome, Outcome::Solved(Answer::from(1u32)));
+    }
+}
@@ -0,0 +1,247 @@
+use std::collections::BTreeSet;
+
+use crate::{
+    grid::{Grid, Position, ORTHOGONAL},
+    union_find::UnionFind,
+};
+
+/// Which neighbours of a cell count as touching it
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Connectivity {
+    Orthogonal,
+    /// Orthogonal and diagonal
+    Surrounding,
+}
+
+impl Connectivity {
+    /// Offsets to the neighbours after a cell in reading order, so that visiting every cell joins
+    /// each touching pair exactly once
+    fn forward_offsets(self) -> &'static [(isize, isize)] {
+        match self {
+            Connectivity::Orthogonal => &[(1, 0), (0, 1)],
+            Connectivity::Surrounding => &[(1, 0), (-1, 1), (0, 1), (1, 1)],
+        }
+    }
+}
+
+/// The smallest rectangle holding a set of cells, with all four edges inclusive
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Bounds {
+    pub left: usize,
+    pub top: usize,
+    pub right: usize,
+    pub bottom: usize,
+}
+
+impl Bounds {
+    fn around((x, y): Position) -> Bounds {
+        Bounds {
+            left: x,
+            top: y,
+            right: x,
+            bottom: y,
+        }
+    }
+
+    fn include(&mut self, (x, y): Position) {
+        self.left = self.left.min(x);
+        self.top = self.top.min(y);
+        self.right = self.right.max(x);
+        self.bottom = self.bottom.max(y);
+    }
+
+    pub fn width(&self) -> usize {
+        self.right - self.left + 1
+    }
+
+    pub fn height(&self) -> usize {
+        self.bottom - self.top + 1
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Region {
+    pub id: usize,
+    /// Every cell in the region, in reading order
+    pub cells: Vec<Position>,
+    pub bounds: Bounds,
+}
+
+impl Region {
+    pub fn area(&self) -> usize {
+        self.cells.len()
+    }
+}
+
+/// A grid split into connected regions. Labelling joins touching cells in a union-find rather than
+/// flood filling, so a region as large as the whole grid needs no recursion
+#[derive(Clone, Debug)]
+pub struct Regions {
+    ids: Grid<usize>,
+    regions: Vec<Region>,
+}
+
+impl Regions {
+    /// Labels the regions of `grid`, where two touching cells are in the same region if `joined`
+    /// holds for them. Regions are numbered in the reading order of their first cell
+    pub fn find<T, F>(grid: &Grid<T>, connectivity: Connectivity, mut joined: F) -> Regions
+    where
+        F: FnMut(&T, &T) -> bool,
+    {
+        let mut sets = UnionFind::new(grid.width() * grid.height());
+        for (position, cell) in grid.iter() {
+            for &offset in connectivity.forward_offsets() {
+                if let Some(next) = grid.offset(position, offset) {
+                    if joined(cell, &grid[next]) {
+                        sets.union(grid.cell_index(position), grid.cell_index(next));
+                    }
+                }
+            }
+        }
+
+        let mut ids_by_root = vec![None; sets.len()];
+        let mut ids = Grid::new(grid.width(), grid.height(), 0);
+        let mut regions: Vec<Region> = Vec::new();
+        for position in grid.positions() {
+            let root = sets.find(grid.cell_index(position));
+            let id = *ids_by_root[root].get_or_insert(regions.len());
+            if id == regions.len() {
+                regions.push(Region {
+                    id,
+                    cells: Vec::new(),
+                    bounds: Bounds::around(position),
+                });
+            }
+            ids[position] = id;
+            regions[id].cells.push(position);
+            regions[id].bounds.include(position);
+        }
+        Regions { ids, regions }
+    }
+
+    /// Labels the regions of touching cells with equal values
+    pub fn of_equal<T: PartialEq>(grid: &Grid<T>, connectivity: Connectivity) -> Regions {
+        Regions::find(grid, connectivity, |a, b| a == b)
+    }
+
+    pub fn len(&self) -> usize {
+        self.regions.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.regions.is_empty()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &Region> {
+        self.regions.iter()
+    }
+
+    pub fn get(&self, id: usize) -> Option<&Region> {
+        self.regions.get(id)
+    }
+
+    /// The id of the region holding `position`, if it is inside the grid
+    pub fn id_at(&self, position: Position) -> Option<usize> {
+        self.ids.get(position).copied()
+    }
+
+    pub fn region_at(&self, position: Position) -> Option<&Region> {
+        self.regions.get(self.id_at(position)?)
+    }
+
+    /// The number of cell edges between a region and anything outside it, including the edge of
+    /// the grid
+    pub fn perimeter(&self, id: usize) -> usize {
+        self.regions[id]
+            .cells
+            .iter()
+            .map(|&position| {
+                ORTHOGONAL
+                    .iter()
+                    .filter(|&&offset| {
+                        let neighbour = self.ids.offset(position, offset);
+                        neighbour.map(|neighbour| self.ids[neighbour]) != Some(id)
+                    })
+                    .count()
+            })
+            .sum()
+    }
+
+    /// For each region, the ids of the other regions it shares an orthogonal edge with
+    pub fn adjacency(&self) -> Vec<BTreeSet<usize>> {
+        let mut adjacent = vec![BTreeSet::new(); self.regions.len()];
+        for (position, &id) in self.ids.iter() {
+            for &offset in Connectivity::Orthogonal.forward_offsets() {
+                if let Some(next) = self.ids.offset(position, offset) {
+                    let other = self.ids[next];
+                    if other != id {
+                        adjacent[id].insert(other);
+                        adjacent[other].insert(id);
+                    }
+                }
+            }
+        }
+        adjacent
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse::{char_grid, non_blank_lines};
+
+    const GARDEN: &str = "
+        AAAA
+        BBCD
+        BBCC
+        EEEC
+    ";
+
+    #[test]
+    fn test_regions() {
+        let grid = char_grid(non_blank_lines(GARDEN)).unwrap();
+        let regions = Regions::of_equal(&grid, Connectivity::Orthogonal);
+        assert_eq!(regions.len(), 5);
+        let areas = regions.iter().map(Region::area).collect::<Vec<_>>();
+        assert_eq!(areas, vec![4, 4, 4, 1, 3]);
+        let perimeters = (0..regions.len())
+            .map(|id| regions.perimeter(id))
+            .collect::<Vec<_>>();
+        assert_eq!(perimeters, vec![10, 8, 10, 4, 8]);
+
+        let c = regions.region_at((2, 1)).unwrap();
+        assert_eq!(
+            c.bounds,
+            Bounds {
+                left: 2,
+                top: 1,
+                right: 3,
+                bottom: 3
+            }
+        );
+        assert_eq!((c.bounds.width(), c.bounds.height()), (2, 3));
+        assert_eq!(regions.id_at((9, 9)), None);
+
+        let adjacency = regions.adjacency();
+        assert_eq!(adjacency[c.id], BTreeSet::from([0, 1, 3, 4]));
+        assert_eq!(adjacency[3], BTreeSet::from([0, 2]));
+    }
+
+    #[test]
+    fn test_diagonal_connectivity() {
+        let grid = char_grid(non_blank_lines("#..\n.#.\n..#")).unwrap();
+        let orthogonal = Regions::of_equal(&grid, Connectivity::Orthogonal);
+        let surrounding = Regions::of_equal(&grid, Connectivity::Surrounding);
+        assert_eq!(orthogonal.len(), 5);
+        assert_eq!(surrounding.len(), 2);
+        assert_eq!(surrounding.region_at((2, 2)).unwrap().area(), 3);
+    }
+
+    #[test]
+    fn test_single_huge_region() {
+        let grid = Grid::new(1000, 1000, 'A');
+        let regions = Regions::of_equal(&grid, Connectivity::Orthogonal);
+        assert_eq!(regions.len(), 1);
+        assert_eq!(regions.perimeter(0), 4000);
+    }
+}
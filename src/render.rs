@@ -0,0 +1,446 @@
+use std::{fmt, fs, io, path::Path};
+
+use crate::{
+    geometry::Direction,
+    grid::{Grid, Position},
+    regions::{Bounds, Regions},
+};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Colour {
+    pub r: u8,
+    pub g: u8,
+    pub b: u8,
+}
+
+impl Colour {
+    pub const BLACK: Colour = Colour::rgb(0, 0, 0);
+    pub const WHITE: Colour = Colour::rgb(255, 255, 255);
+    pub const GREY: Colour = Colour::rgb(128, 128, 128);
+    pub const RED: Colour = Colour::rgb(220, 50, 47);
+    pub const GREEN: Colour = Colour::rgb(80, 200, 80);
+    pub const BLUE: Colour = Colour::rgb(60, 120, 230);
+    pub const YELLOW: Colour = Colour::rgb(240, 200, 40);
+    pub const ORANGE: Colour = Colour::rgb(230, 130, 30);
+
+    pub const fn rgb(r: u8, g: u8, b: u8) -> Colour {
+        Colour { r, g, b }
+    }
+
+    /// A colour for the `index`th of many things, such as regions. Hues step round the colour
+    /// wheel by the golden angle, so that consecutive indices are never alike
+    pub fn palette(index: usize) -> Colour {
+        let hue = (index as f64 * 137.507_764) % 360.0;
+        let (saturation, value) = (0.55, 0.9);
+        let chroma = value * saturation;
+        let x = chroma * (1.0 - ((hue / 60.0) % 2.0 - 1.0).abs());
+        let (r, g, b) = match (hue / 60.0) as u32 {
+            0 => (chroma, x, 0.0),
+            1 => (x, chroma, 0.0),
+            2 => (0.0, chroma, x),
+            3 => (0.0, x, chroma),
+            4 => (x, 0.0, chroma),
+            _ => (chroma, 0.0, x),
+        };
+        let channel = |c: f64| ((c + value - chroma) * 255.0).round() as u8;
+        Colour::rgb(channel(r), channel(g), channel(b))
+    }
+}
+
+/// Writes the colour as `#rrggbb`, as in HTML and SVG
+impl fmt::Display for Colour {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "#{:02x}{:02x}{:02x}", self.r, self.g, self.b)
+    }
+}
+
+/// A single character of a rendering, with optional colours for the character and behind it
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Cell {
+    pub glyph: char,
+    pub foreground: Option<Colour>,
+    pub background: Option<Colour>,
+}
+
+impl Cell {
+    pub const fn plain(glyph: char) -> Cell {
+        Cell {
+            glyph,
+            foreground: None,
+            background: None,
+        }
+    }
+
+    /// The colour of the cell as a block of pixels: its background if it has one, otherwise its
+    /// character's colour. Uncoloured empty cells are black and anything else is grey
+    pub fn pixel(&self) -> Colour {
+        match (self.background, self.foreground, self.glyph) {
+            (Some(colour), _, _) | (None, Some(colour), _) => colour,
+            (None, None, ' ' | '.') => Colour::BLACK,
+            (None, None, _) => Colour::GREY,
+        }
+    }
+}
+
+/// A grid of characters to be drawn over in layers, such as paths, highlighted cells and
+/// coloured regions, and then shown in a terminal or saved as an image
+#[derive(Clone, Debug, PartialEq)]
+pub struct Canvas {
+    cells: Grid<Cell>,
+}
+
+impl Canvas {
+    /// A canvas showing each cell of `grid` as `style` draws it
+    pub fn new<T, F: FnMut(&T) -> Cell>(grid: &Grid<T>, style: F) -> Canvas {
+        Canvas {
+            cells: grid.map(style),
+        }
+    }
+
+    pub fn from_chars(grid: &Grid<char>) -> Canvas {
+        Canvas::new(grid, |&glyph| Cell::plain(glyph))
+    }
+
+    /// An empty canvas of `.` cells
+    pub fn blank(width: usize, height: usize) -> Canvas {
+        Canvas {
+            cells: Grid::new(width, height, Cell::plain('.')),
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.cells.width()
+    }
+
+    pub fn height(&self) -> usize {
+        self.cells.height()
+    }
+
+    pub fn cells(&self) -> &Grid<Cell> {
+        &self.cells
+    }
+
+    /// The part of the canvas inside `bounds`, clipped to the canvas, or `None` if they don't
+    /// overlap at all
+    pub fn crop(&self, bounds: Bounds) -> Option<Canvas> {
+        let right = bounds.right.min(self.width().checked_sub(1)?);
+        let bottom = bounds.bottom.min(self.height().checked_sub(1)?);
+        if bounds.left > right || bounds.top > bottom {
+            return None;
+        }
+        let rows = (bounds.top..=bottom)
+            .map(|y| self.cells.row(y)[bounds.left..=right].to_vec())
+            .collect();
+        let cells = Grid::from_rows(rows);
+        Some(Canvas { cells })
+    }
+
+    /// Draws `glyph` in `colour` at each of `positions`, ignoring any off the canvas
+    pub fn mark<I>(&mut self, positions: I, glyph: char, colour: Option<Colour>) -> &mut Canvas
+    where
+        I: IntoIterator<Item = Position>,
+    {
+        for position in positions {
+            if let Some(cell) = self.cells.get_mut(position) {
+                cell.glyph = glyph;
+                cell.foreground = colour;
+            }
+        }
+        self
+    }
+
+    /// Colours the characters at each of `positions`, leaving the characters as they are
+    pub fn tint<I>(&mut self, positions: I, colour: Colour) -> &mut Canvas
+    where
+        I: IntoIterator<Item = Position>,
+    {
+        for position in positions {
+            if let Some(cell) = self.cells.get_mut(position) {
+                cell.foreground = Some(colour);
+            }
+        }
+        self
+    }
+
+    /// Colours the background behind each of `positions`
+    pub fn highlight<I>(&mut self, positions: I, colour: Colour) -> &mut Canvas
+    where
+        I: IntoIterator<Item = Position>,
+    {
+        for position in positions {
+            if let Some(cell) = self.cells.get_mut(position) {
+                cell.background = Some(colour);
+            }
+        }
+        self
+    }
+
+    /// Draws a path as an arrow at each step, pointing the way it was heading
+    pub fn path<I>(&mut self, steps: I, colour: Colour) -> &mut Canvas
+    where
+        I: IntoIterator<Item = (Position, Direction)>,
+    {
+        for (position, direction) in steps {
+            self.mark([position], direction.arrow(), Some(colour));
+        }
+        self
+    }
+
+    /// Gives each region its own background colour from the palette
+    pub fn regions(&mut self, regions: &Regions) -> &mut Canvas {
+        for region in regions.iter() {
+            self.highlight(region.cells.iter().copied(), Colour::palette(region.id));
+        }
+        self
+    }
+
+    /// Just the characters, one line per row
+    pub fn to_text(&self) -> String {
+        self.cells.map(|cell| cell.glyph).to_string()
+    }
+
+    /// The characters with their colours as 24-bit ANSI escape codes, for a terminal
+    pub fn to_ansi(&self) -> String {
+        let mut text = String::new();
+        for row in self.cells.rows() {
+            for cell in row {
+                let styled = cell.foreground.is_some() || cell.background.is_some();
+                if let Some(Colour { r, g, b }) = cell.foreground {
+                    text += &format!("\x1b[38;2;{r};{g};{b}m");
+                }
+                if let Some(Colour { r, g, b }) = cell.background {
+                    text += &format!("\x1b[48;2;{r};{g};{b}m");
+                }
+                text.push(cell.glyph);
+                if styled {
+                    text += "\x1b[0m";
+                }
+            }
+            text.push('\n');
+        }
+        text
+    }
+
+    /// Paints each cell as a `scale` by `scale` block of pixels
+    pub fn to_image(&self, scale: usize) -> Image {
+        let mut image = Image::new(self.width() * scale, self.height() * scale);
+        for ((x, y), cell) in self.cells.iter() {
+            let colour = cell.pixel();
+            for dy in 0..scale {
+                for dx in 0..scale {
+                    image.set((x * scale + dx, y * scale + dy), colour);
+                }
+            }
+        }
+        image
+    }
+}
+
+/// An RGB picture, which can be written as a PPM or a PNG without any image libraries
+#[derive(Clone, Debug, PartialEq)]
+pub struct Image {
+    pub width: usize,
+    pub height: usize,
+    /// Red, green and blue bytes for each pixel, row by row
+    pub pixels: Vec<u8>,
+}
+
+impl Image {
+    /// A black image
+    pub fn new(width: usize, height: usize) -> Image {
+        Image {
+            width,
+            height,
+            pixels: vec![0; width * height * 3],
+        }
+    }
+
+    pub fn set(&mut self, (x, y): Position, colour: Colour) {
+        let index = (y * self.width + x) * 3;
+        self.pixels[index..index + 3].copy_from_slice(&[colour.r, colour.g, colour.b]);
+    }
+
+    /// A binary PPM (P6)
+    pub fn to_ppm(&self) -> Vec<u8> {
+        let mut bytes = format!("P6\n{} {}\n255\n", self.width, self.height).into_bytes();
+        bytes.extend(&self.pixels);
+        bytes
+    }
+
+    /// A PNG with the image data stored uncompressed inside the zlib stream
+    pub fn to_png(&self) -> Vec<u8> {
+        let mut header = Vec::new();
+        header.extend((self.width as u32).to_be_bytes());
+        header.extend((self.height as u32).to_be_bytes());
+        // 8 bits per channel, truecolour, default compression, filter and no interlacing
+        header.extend([8, 2, 0, 0, 0]);
+
+        // Each row of pixels is preceded by its filter type, which is always none
+        let mut scanlines = Vec::with_capacity(self.height * (self.width * 3 + 1));
+        for row in self.pixels.chunks(self.width * 3) {
+            scanlines.push(0);
+            scanlines.extend(row);
+        }
+
+        let mut png = b"\x89PNG\r\n\x1a\n".to_vec();
+        write_chunk(&mut png, b"IHDR", &header);
+        write_chunk(&mut png, b"IDAT", &zlib_stored(&scanlines));
+        write_chunk(&mut png, b"IEND", &[]);
+        png
+    }
+
+    /// Writes the image as a PNG or a PPM, chosen by the extension of `path`
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        let extension = path.extension().and_then(|extension| extension.to_str());
+        let bytes = match extension.map(str::to_ascii_lowercase).as_deref() {
+            Some("png") => self.to_png(),
+            Some("ppm") => self.to_ppm(),
+            _ => {
+                let message = format!("{} should end in .png or .ppm", path.display());
+                return Err(io::Error::new(io::ErrorKind::InvalidInput, message));
+            }
+        };
+        fs::write(path, bytes)
+    }
+}
+
+fn write_chunk(png: &mut Vec<u8>, kind: &[u8; 4], data: &[u8]) {
+    png.extend((data.len() as u32).to_be_bytes());
+    let start = png.len();
+    png.extend(kind);
+    png.extend(data);
+    let crc = crc32(&png[start..]);
+    png.extend(crc.to_be_bytes());
+}
+
+/// Wraps `data` in a zlib stream of uncompressed deflate blocks
+fn zlib_stored(data: &[u8]) -> Vec<u8> {
+    // Deflate with a 32K window and the fastest compression level
+    let mut stream = vec![0x78, 0x01];
+    let mut blocks = data.chunks(u16::MAX as usize).peekable();
+    if blocks.peek().is_none() {
+        stream.extend([1, 0, 0, 0xff, 0xff]);
+    }
+    while let Some(block) = blocks.next() {
+        let last = blocks.peek().is_none();
+        let length = block.len() as u16;
+        stream.push(last as u8);
+        stream.extend(length.to_le_bytes());
+        stream.extend((!length).to_le_bytes());
+        stream.extend(block);
+    }
+    stream.extend(adler32(data).to_be_bytes());
+    stream
+}
+
+fn adler32(data: &[u8]) -> u32 {
+    const MODULUS: u32 = 65521;
+    let (mut a, mut b) = (1, 0);
+    for &byte in data {
+        a = (a + byte as u32) % MODULUS;
+        b = (b + a) % MODULUS;
+    }
+    (b << 16) | a
+}
+
+fn crc32(data: &[u8]) -> u32 {
+    let mut crc = !0u32;
+    for &byte in data {
+        crc ^= byte as u32;
+        for _ in 0..8 {
+            let mask = (crc & 1).wrapping_neg();
+            crc = (crc >> 1) ^ (0xedb8_8320 & mask);
+        }
+    }
+    !crc
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        parse::{char_grid, non_blank_lines},
+        regions::Connectivity,
+    };
+
+    #[test]
+    fn test_checksums() {
+        assert_eq!(adler32(b"Wikipedia"), 0x11e6_0398);
+        assert_eq!(crc32(b"IEND"), 0xae42_6082);
+        assert_eq!(crc32(b"123456789"), 0xcbf4_3926);
+    }
+
+    #[test]
+    fn test_png_layout() {
+        let mut image = Image::new(2, 1);
+        image.set((1, 0), Colour::WHITE);
+        let png = image.to_png();
+        assert_eq!(&png[..8], b"\x89PNG\r\n\x1a\n");
+        assert_eq!(&png[12..16], b"IHDR");
+        assert_eq!(&png[16..24], &[0, 0, 0, 2, 0, 0, 0, 1]);
+        // IEND is empty, so always ends the file with the same twelve bytes
+        assert_eq!(
+            &png[png.len() - 12..],
+            &[0, 0, 0, 0, b'I', b'E', b'N', b'D', 0xae, 0x42, 0x60, 0x82]
+        );
+        let idat = &png[33..];
+        assert_eq!(&idat[4..8], b"IDAT");
+        let stream = &idat[8..8 + u32::from_be_bytes(idat[..4].try_into().unwrap()) as usize];
+        assert_eq!(&stream[..7], &[0x78, 0x01, 1, 7, 0, 0xf8, 0xff]);
+        assert_eq!(&stream[7..14], &[0, 0, 0, 0, 255, 255, 255]);
+
+        assert_eq!(image.to_ppm(), b"P6\n2 1\n255\n\0\0\0\xff\xff\xff");
+    }
+
+    #[test]
+    fn test_layers() {
+        let grid = char_grid(non_blank_lines("#..\n.#.")).unwrap();
+        let mut canvas = Canvas::from_chars(&grid);
+        canvas
+            .path([((0, 1), Direction::Right)], Colour::RED)
+            .highlight([(2, 0)], Colour::BLUE);
+        assert_eq!(canvas.to_text(), "#..\n>#.\n");
+        assert_eq!(
+            canvas.to_ansi().lines().collect::<Vec<_>>(),
+            [
+                "#.\x1b[48;2;60;120;230m.\x1b[0m",
+                "\x1b[38;2;220;50;47m>\x1b[0m#."
+            ]
+        );
+
+        let image = canvas.to_image(2);
+        assert_eq!((image.width, image.height), (6, 4));
+        assert_eq!(&image.pixels[..3], &[128, 128, 128]);
+        assert_eq!(&image.pixels[(2 * 6 + 1) * 3..][..3], &[220, 50, 47]);
+        assert_eq!(&image.pixels[5 * 3..][..3], &[60, 120, 230]);
+    }
+
+    #[test]
+    fn test_crop() {
+        let canvas = Canvas::from_chars(&char_grid(non_blank_lines("abc\ndef\nghi")).unwrap());
+        let bounds = |left, top, right, bottom| Bounds {
+            left,
+            top,
+            right,
+            bottom,
+        };
+        let cropped = canvas.crop(bounds(1, 1, 5, 5)).unwrap();
+        assert_eq!(cropped.to_text(), "ef\nhi\n");
+        assert_eq!(
+            canvas.crop(bounds(0, 0, 0, 2)).unwrap().to_text(),
+            "a\nd\ng\n"
+        );
+        assert_eq!(canvas.crop(bounds(3, 0, 4, 1)), None);
+    }
+
+    #[test]
+    fn test_regions_are_coloured_apart() {
+        let grid = char_grid(non_blank_lines("aab\nabb")).unwrap();
+        let mut canvas = Canvas::from_chars(&grid);
+        canvas.regions(&Regions::of_equal(&grid, Connectivity::Orthogonal));
+        let colours = canvas.cells().map(|cell| cell.pixel());
+        assert_eq!(colours[(0, 0)], colours[(0, 1)]);
+        assert_ne!(colours[(0, 0)], colours[(2, 0)]);
+        assert_eq!(colours[(0, 0)], Colour::palette(0));
+    }
+}
@@ -0,0 +1,187 @@
+use std::{fs, io, path::Path};
+
+use crate::{grid::Position, render::Colour};
+
+/// Pixels across each cell of a map
+const CELL: usize = 10;
+
+/// A vector drawing of a map of cells, built up in layers with later ones drawn on top, and
+/// written out as SVG
+#[derive(Clone, Debug, PartialEq)]
+pub struct Drawing {
+    width: usize,
+    height: usize,
+    elements: Vec<String>,
+}
+
+/// A drawing with a name to save it under
+#[derive(Clone, Debug, PartialEq)]
+pub struct Figure {
+    pub name: &'static str,
+    pub drawing: Drawing,
+}
+
+fn centre((x, y): Position) -> (usize, usize) {
+    (x * CELL + CELL / 2, y * CELL + CELL / 2)
+}
+
+impl Drawing {
+    /// An empty drawing of a map `width` by `height` cells
+    pub fn new(width: usize, height: usize) -> Drawing {
+        Drawing {
+            width,
+            height,
+            elements: Vec::new(),
+        }
+    }
+
+    /// Fills each of `positions`, such as the walls of a maze
+    pub fn cells<I>(&mut self, positions: I, colour: Colour) -> &mut Drawing
+    where
+        I: IntoIterator<Item = Position>,
+    {
+        let rects = positions
+            .into_iter()
+            .map(|(x, y)| {
+                let (x, y) = (x * CELL, y * CELL);
+                format!("<rect x=\"{x}\" y=\"{y}\" width=\"{CELL}\" height=\"{CELL}\"/>")
+            })
+            .collect::<String>();
+        self.elements
+            .push(format!("<g fill=\"{colour}\">{rects}</g>"));
+        self
+    }
+
+    /// A line through the centres of `positions` in order
+    pub fn route(&mut self, positions: &[Position], colour: Colour) -> &mut Drawing {
+        let points = positions
+            .iter()
+            .map(|&position| {
+                let (x, y) = centre(position);
+                format!("{x},{y}")
+            })
+            .collect::<Vec<_>>()
+            .join(" ");
+        self.elements.push(format!(
+            "<polyline points=\"{points}\" fill=\"none\" stroke=\"{colour}\" stroke-width=\"4\" \
+             stroke-linecap=\"round\" stroke-linejoin=\"round\"/>"
+        ));
+        self
+    }
+
+    /// A separate straight line between the centres of each pair of cells
+    pub fn segments<I>(&mut self, pairs: I, colour: Colour) -> &mut Drawing
+    where
+        I: IntoIterator<Item = (Position, Position)>,
+    {
+        let path = pairs
+            .into_iter()
+            .map(|(from, to)| {
+                let ((x1, y1), (x2, y2)) = (centre(from), centre(to));
+                format!("M{x1} {y1}L{x2} {y2}")
+            })
+            .collect::<String>();
+        self.elements.push(format!(
+            "<path d=\"{path}\" fill=\"none\" stroke=\"{colour}\" stroke-width=\"4\" \
+             stroke-linecap=\"round\"/>"
+        ));
+        self
+    }
+
+    /// A curve from the first cell of each pair to the second, bowing out to the left of the
+    /// straight line between them so that overlapping jumps stay apart
+    pub fn arcs<I>(&mut self, pairs: I, colour: Colour) -> &mut Drawing
+    where
+        I: IntoIterator<Item = (Position, Position)>,
+    {
+        let path = pairs
+            .into_iter()
+            .map(|(from, to)| {
+                let ((x1, y1), (x2, y2)) = (centre(from), centre(to));
+                let (x1, y1, x2, y2) = (x1 as f64, y1 as f64, x2 as f64, y2 as f64);
+                // The midpoint, pushed sideways by a third of the jump's length
+                let (cx, cy) = (
+                    (x1 + x2) / 2.0 + (y2 - y1) / 3.0,
+                    (y1 + y2) / 2.0 - (x2 - x1) / 3.0,
+                );
+                format!("M{x1} {y1}Q{cx:.1} {cy:.1} {x2} {y2}")
+            })
+            .collect::<String>();
+        self.elements.push(format!(
+            "<path d=\"{path}\" fill=\"none\" stroke=\"{colour}\" stroke-width=\"1\" \
+             stroke-opacity=\"0.6\"/>"
+        ));
+        self
+    }
+
+    /// A dot in the middle of a cell, such as the start or end of a route
+    pub fn dot(&mut self, position: Position, colour: Colour) -> &mut Drawing {
+        let (x, y) = centre(position);
+        let radius = CELL * 2 / 5;
+        self.elements.push(format!(
+            "<circle cx=\"{x}\" cy=\"{y}\" r=\"{radius}\" fill=\"{colour}\"/>"
+        ));
+        self
+    }
+
+    pub fn to_svg(&self) -> String {
+        let (width, height) = (self.width * CELL, self.height * CELL);
+        let mut svg = format!(
+            "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{width}\" height=\"{height}\" \
+             viewBox=\"0 0 {width} {height}\">\n"
+        );
+        svg += &format!(
+            "<rect width=\"{width}\" height=\"{height}\" fill=\"{}\"/>\n",
+            Colour::WHITE
+        );
+        for element in &self.elements {
+            svg += element;
+            svg.push('\n');
+        }
+        svg += "</svg>\n";
+        svg
+    }
+
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        fs::write(path, self.to_svg())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_layers() {
+        let mut drawing = Drawing::new(3, 2);
+        drawing
+            .cells([(1, 0)], Colour::BLACK)
+            .route(&[(0, 0), (0, 1), (2, 1)], Colour::BLUE)
+            .segments([((2, 1), (2, 0))], Colour::ORANGE)
+            .dot((2, 0), Colour::RED);
+        let svg = drawing.to_svg();
+        let lines = svg.lines().collect::<Vec<_>>();
+        assert!(lines[0].starts_with("<svg ") && lines[0].contains("viewBox=\"0 0 30 20\""));
+        assert_eq!(
+            lines[2],
+            "<g fill=\"#000000\"><rect x=\"10\" y=\"0\" width=\"10\" height=\"10\"/></g>"
+        );
+        assert!(lines[3]
+            .starts_with("<polyline points=\"5,5 5,15 25,15\" fill=\"none\" stroke=\"#3c78e6\""));
+        assert!(lines[4].starts_with("<path d=\"M25 15L25 5\""));
+        assert_eq!(
+            lines[5],
+            "<circle cx=\"25\" cy=\"5\" r=\"4\" fill=\"#dc322f\"/>"
+        );
+        assert_eq!(lines.last(), Some(&"</svg>"));
+    }
+
+    #[test]
+    fn test_arcs_bow_to_one_side() {
+        let mut drawing = Drawing::new(5, 1);
+        drawing.arcs([((0, 0), (3, 0))], Colour::GREEN);
+        let svg = drawing.to_svg();
+        // A jump to the right bows upwards, by a third of its 30 pixels
+        assert!(svg.contains("<path d=\"M5 5Q20.0 -5.0 35 5\""));
+    }
+}
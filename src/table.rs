@@ -0,0 +1,87 @@
+use std::fmt;
+
+/// Plain-text table with left-aligned, padded columns for the runner's reports
+pub struct Table {
+    headers: Vec<String>,
+    rows: Vec<Vec<String>>,
+}
+
+impl Table {
+    pub fn new<S: AsRef<str>>(headers: &[S]) -> Table {
+        Table {
+            headers: headers.iter().map(|h| h.as_ref().to_string()).collect(),
+            rows: Vec::new(),
+        }
+    }
+
+    pub fn add_row(&mut self, row: Vec<String>) {
+        self.rows.push(row);
+    }
+
+    fn column_widths(&self) -> Vec<usize> {
+        let mut widths = self
+            .headers
+            .iter()
+            .map(|h| h.chars().count())
+            .collect::<Vec<_>>();
+        for row in &self.rows {
+            for (i, cell) in row.iter().enumerate() {
+                if i < widths.len() {
+                    widths[i] = std::cmp::max(widths[i], cell.chars().count());
+                } else {
+                    widths.push(cell.chars().count());
+                }
+            }
+        }
+        widths
+    }
+}
+
+fn write_row(f: &mut fmt::Formatter, cells: &[String], widths: &[usize]) -> fmt::Result {
+    let line = widths
+        .iter()
+        .enumerate()
+        .map(|(i, &width)| {
+            let cell = cells.get(i).map(|c| c.as_str()).unwrap_or("");
+            format!("{cell:<width$}")
+        })
+        .collect::<Vec<String>>()
+        .join("  ");
+    writeln!(f, "{}", line.trim_end())
+}
+
+impl fmt::Display for Table {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let widths = self.column_widths();
+        write_row(f, &self.headers, &widths)?;
+        let separator = widths
+            .iter()
+            .map(|&width| "-".repeat(width))
+            .collect::<Vec<String>>();
+        write_row(f, &separator, &widths)?;
+        for row in &self.rows {
+            write_row(f, row, &widths)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_columns_are_padded_to_widest_cell() {
+        let mut table = Table::new(&["Set", "Part 1"]);
+        table.add_row(vec![String::from("default"), String::from("11")]);
+        table.add_row(vec![String::from("bob"), String::from("123456789")]);
+        assert_eq!(
+            table.to_string(),
+            "Set      Part 1
+-------  ---------
+default  11
+bob      123456789
+"
+        );
+    }
+}
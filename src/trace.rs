@@ -0,0 +1,269 @@
+use std::{
+    fmt,
+    str::FromStr,
+    sync::{
+        atomic::{AtomicU8, Ordering},
+        RwLock,
+    },
+};
+
+/// How important a message is, from most to least
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Level {
+    Error = 1,
+    Warn,
+    Info,
+    Debug,
+    Trace,
+}
+
+impl Level {
+    const ALL: [Level; 5] = [
+        Level::Error,
+        Level::Warn,
+        Level::Info,
+        Level::Debug,
+        Level::Trace,
+    ];
+
+    /// The next more detailed level, if there is one
+    fn more(self) -> Level {
+        Level::ALL
+            .into_iter()
+            .find(|&level| level > self)
+            .unwrap_or(self)
+    }
+}
+
+impl fmt::Display for Level {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let name = match self {
+            Level::Error => "ERROR",
+            Level::Warn => "WARN",
+            Level::Info => "INFO",
+            Level::Debug => "DEBUG",
+            Level::Trace => "TRACE",
+        };
+        f.pad(name)
+    }
+}
+
+impl FromStr for Level {
+    type Err = String;
+
+    fn from_str(text: &str) -> Result<Level, String> {
+        Level::ALL
+            .into_iter()
+            .find(|level| level.to_string().eq_ignore_ascii_case(text))
+            .ok_or(format!("Unrecognised trace level {text}"))
+    }
+}
+
+/// Which messages are written: those up to a level everywhere, or nothing for `None`, with more or
+/// less detail for particular targets
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Filter {
+    pub level: Option<Level>,
+    pub targets: Vec<(String, Level)>,
+}
+
+impl Default for Filter {
+    /// Warnings and errors from everywhere
+    fn default() -> Filter {
+        Filter::new()
+    }
+}
+
+impl Filter {
+    const fn new() -> Filter {
+        Filter {
+            level: Some(Level::Warn),
+            targets: Vec::new(),
+        }
+    }
+
+    /// Whether a message at `level` from `target` gets written
+    pub fn enabled(&self, level: Level, target: &str) -> bool {
+        let limit = self
+            .targets
+            .iter()
+            .rev()
+            .find(|(name, _)| name == target)
+            .map_or(self.level, |&(_, limit)| Some(limit));
+        limit.is_some_and(|limit| level <= limit)
+    }
+
+    /// The most detailed level written for any target
+    fn most_detailed(&self) -> Option<Level> {
+        let targets = self.targets.iter().map(|&(_, limit)| Some(limit));
+        targets.chain([self.level]).max().flatten()
+    }
+
+    /// Writes one level more detail everywhere
+    pub fn verbose(&mut self) {
+        self.level = Some(self.level.map_or(Level::Error, Level::more));
+    }
+
+    /// Adds a target such as `day11`, written in full, or `day11=debug`, written up to a level
+    pub fn add_target(&mut self, spec: &str) -> Result<(), String> {
+        let (target, level) = match spec.split_once('=') {
+            Some((target, level)) => (target, level.parse()?),
+            None => (spec, Level::Trace),
+        };
+        if target.is_empty() {
+            return Err(format!("Trace target missing from {spec}"));
+        }
+        self.targets.push((target.to_string(), level));
+        Ok(())
+    }
+
+    /// Applies `flag` if it is one of the tracing flags, taking any value it needs from `args`,
+    /// and returns whether it was: `-v` for more detail (repeatable, or as `-vv`), `--trace
+    /// <target[=level]>` and `--quiet` for nothing at all
+    pub fn apply_flag(
+        &mut self,
+        flag: &str,
+        args: &mut impl Iterator<Item = String>,
+    ) -> Result<bool, String> {
+        match flag {
+            "--verbose" => self.verbose(),
+            "--quiet" | "-q" => {
+                self.level = None;
+                self.targets.clear();
+            }
+            "--trace" => self.add_target(&args.next().ok_or("--trace requires a target")?)?,
+            _ if flag.len() > 1
+                && flag.starts_with('-')
+                && flag[1..].bytes().all(|b| b == b'v') =>
+            {
+                for _ in 1..flag.len() {
+                    self.verbose();
+                }
+            }
+            _ => return Ok(false),
+        }
+        Ok(true)
+    }
+}
+
+static FILTER: RwLock<Filter> = RwLock::new(Filter::new());
+/// The most detailed level any target is written at, so that most messages are passed over
+/// without taking the lock
+static MOST_DETAILED: AtomicU8 = AtomicU8::new(Level::Warn as u8);
+
+/// Replaces the global filter
+pub fn set_filter(filter: Filter) {
+    let most_detailed = filter.most_detailed().map_or(0, |level| level as u8);
+    *FILTER
+        .write()
+        .unwrap_or_else(|poisoned| poisoned.into_inner()) = filter;
+    MOST_DETAILED.store(most_detailed, Ordering::Relaxed);
+}
+
+/// Whether the global filter writes a message at `level` from `target`
+pub fn enabled(level: Level, target: &str) -> bool {
+    if level as u8 > MOST_DETAILED.load(Ordering::Relaxed) {
+        return false;
+    }
+    let filter = FILTER
+        .read()
+        .unwrap_or_else(|poisoned| poisoned.into_inner());
+    filter.enabled(level, target)
+}
+
+/// The target for messages from a module: its last path segment, such as `day11`
+pub fn target(module_path: &str) -> &str {
+    module_path.rsplit("::").next().unwrap_or(module_path)
+}
+
+/// Writes a message to standard error, so that answers on standard output stay clean. Use the
+/// macros, which skip formatting messages the filter drops
+pub fn write(level: Level, target: &str, message: fmt::Arguments) {
+    eprintln!("{level:>5} {target}: {message}");
+}
+
+/// Writes a message at a level from the calling module, if the global filter lets it through
+#[macro_export]
+macro_rules! event {
+    ($level:expr, $($arg:tt)+) => {{
+        let target = $crate::trace::target(module_path!());
+        if $crate::trace::enabled($level, target) {
+            $crate::trace::write($level, target, format_args!($($arg)+));
+        }
+    }};
+}
+
+#[macro_export]
+macro_rules! warn {
+    ($($arg:tt)+) => { $crate::event!($crate::trace::Level::Warn, $($arg)+) };
+}
+
+#[macro_export]
+macro_rules! info {
+    ($($arg:tt)+) => { $crate::event!($crate::trace::Level::Info, $($arg)+) };
+}
+
+#[macro_export]
+macro_rules! debug {
+    ($($arg:tt)+) => { $crate::event!($crate::trace::Level::Debug, $($arg)+) };
+}
+
+#[macro_export]
+macro_rules! trace {
+    ($($arg:tt)+) => { $crate::event!($crate::trace::Level::Trace, $($arg)+) };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn apply(flags: &[&str]) -> Result<Filter, String> {
+        let mut filter = Filter::default();
+        let mut args = flags.iter().map(|flag| flag.to_string());
+        while let Some(flag) = args.next() {
+            if !filter.apply_flag(&flag, &mut args)? {
+                return Err(format!("not a tracing flag: {flag}"));
+            }
+        }
+        Ok(filter)
+    }
+
+    #[test]
+    fn test_levels_and_targets() {
+        let filter = Filter::default();
+        assert!(filter.enabled(Level::Warn, "day17"));
+        assert!(!filter.enabled(Level::Info, "day17"));
+
+        let filter = apply(&["--trace", "day11"]).unwrap();
+        assert!(filter.enabled(Level::Trace, "day11"));
+        assert!(!filter.enabled(Level::Debug, "day12"));
+        assert_eq!(filter.most_detailed(), Some(Level::Trace));
+
+        let filter = apply(&["-vv", "--trace", "day14=warn"]).unwrap();
+        assert!(filter.enabled(Level::Debug, "day11"));
+        assert!(!filter.enabled(Level::Info, "day14"));
+        assert_eq!(
+            apply(&["-v", "-v", "-v", "-v"]).unwrap().level,
+            Some(Level::Trace)
+        );
+
+        let quiet = apply(&["--trace", "day11", "-q"]).unwrap();
+        assert!(!quiet.enabled(Level::Error, "day11"));
+        assert_eq!(quiet.most_detailed(), None);
+        assert_eq!(apply(&["-q", "-v"]).unwrap().level, Some(Level::Error));
+    }
+
+    #[test]
+    fn test_bad_flags() {
+        assert!(apply(&["--trace"]).is_err());
+        assert!(apply(&["--trace", "=debug"]).is_err());
+        assert_eq!(
+            apply(&["--trace", "day11=loud"]),
+            Err(String::from("Unrecognised trace level loud"))
+        );
+        assert!(apply(&["-x"]).is_err());
+        assert!(apply(&["-"]).is_err());
+        assert_eq!("debug".parse(), Ok(Level::Debug));
+        assert_eq!(target("advent_of_code_2024::day11"), "day11");
+    }
+}
@@ -0,0 +1,88 @@
+/// Disjoint sets over the items `0..n`, merged with union by size and found with path halving, so
+/// any sequence of operations runs in near-linear time without recursion
+#[derive(Clone, Debug)]
+pub struct UnionFind {
+    parents: Vec<usize>,
+    sizes: Vec<usize>,
+}
+
+impl UnionFind {
+    /// Starts with every item in a set of its own
+    pub fn new(n: usize) -> UnionFind {
+        UnionFind {
+            parents: (0..n).collect(),
+            sizes: vec![1; n],
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.parents.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.parents.is_empty()
+    }
+
+    /// The representative item of the set containing `item`
+    pub fn find(&mut self, mut item: usize) -> usize {
+        while self.parents[item] != item {
+            self.parents[item] = self.parents[self.parents[item]];
+            item = self.parents[item];
+        }
+        item
+    }
+
+    /// Merges the sets containing `a` and `b`, returning false if they were already the same set
+    pub fn union(&mut self, a: usize, b: usize) -> bool {
+        let (mut a, mut b) = (self.find(a), self.find(b));
+        if a == b {
+            return false;
+        }
+        if self.sizes[a] < self.sizes[b] {
+            std::mem::swap(&mut a, &mut b);
+        }
+        self.parents[b] = a;
+        self.sizes[a] += self.sizes[b];
+        true
+    }
+
+    pub fn connected(&mut self, a: usize, b: usize) -> bool {
+        self.find(a) == self.find(b)
+    }
+
+    /// How many items are in the set containing `item`
+    pub fn size(&mut self, item: usize) -> usize {
+        let root = self.find(item);
+        self.sizes[root]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_union_find() {
+        let mut sets = UnionFind::new(6);
+        assert_eq!(sets.len(), 6);
+        assert!(sets.union(0, 1));
+        assert!(sets.union(2, 3));
+        assert!(sets.union(1, 3));
+        assert!(!sets.union(0, 2));
+        assert!(sets.connected(0, 3));
+        assert!(!sets.connected(0, 4));
+        assert_eq!(sets.size(2), 4);
+        assert_eq!(sets.size(5), 1);
+    }
+
+    #[test]
+    fn test_long_chain_does_not_recurse() {
+        let n = 1_000_000;
+        let mut sets = UnionFind::new(n);
+        for i in 1..n {
+            sets.union(i, i - 1);
+        }
+        assert!(sets.connected(0, n - 1));
+        assert_eq!(sets.size(n / 2), n);
+    }
+}
@@ -0,0 +1,63 @@
+//! Runs every day's solver against the worked examples in `problems/examples`
+
+use std::{fs, panic};
+
+use advent_of_code_2024::{
+    examples::{
+        description_path, extract, fixture_path, is_hand_maintained, load_fixture, to_fixture,
+    },
+    input::data_dir,
+    DAYS,
+};
+
+#[test]
+fn test_description_examples() {
+    let dir = data_dir(None);
+    let mut failures = Vec::new();
+    let mut checked = 0;
+    for day in &DAYS {
+        let examples = load_fixture(&dir, day.number).unwrap();
+        for example in examples.iter().filter(|example| example.skip.is_none()) {
+            let label = format!("day {:02} part {}", day.number, example.part);
+            let solved = panic::catch_unwind(|| {
+                day.solve_with(&example.input, example.part, &example.settings)
+            });
+            match solved.map(|answer| answer.map_err(|error| error.to_string())) {
+                Ok(Ok(answer)) if answer == example.answer => (),
+                Ok(Err(error)) => failures.push(format!("{label}: {error}")),
+                Ok(Ok(answer)) => failures.push(format!(
+                    "{label}: expected {}, got {answer}",
+                    example.answer
+                )),
+                Err(_) => failures.push(format!("{label}: panicked")),
+            }
+            checked += 1;
+        }
+    }
+    assert!(checked > 0, "no examples in {}", dir.display());
+    assert!(failures.is_empty(), "{}", failures.join("\n"));
+}
+
+/// Fixtures that weren't corrected by hand have to be exactly what the extractor writes, so that
+/// regenerating them can't quietly change what the tests check
+#[test]
+fn test_fixtures_match_extraction() {
+    let dir = data_dir(None);
+    let mut stale = Vec::new();
+    for day in &DAYS {
+        let Ok(fixture) = fs::read_to_string(fixture_path(&dir, day.number)) else {
+            continue;
+        };
+        let Ok(description) = fs::read_to_string(description_path(&dir, day.number)) else {
+            continue;
+        };
+        if !is_hand_maintained(&fixture) && to_fixture(&extract(&description)) != fixture {
+            stale.push(format!("{:02}", day.number));
+        }
+    }
+    assert!(
+        stale.is_empty(),
+        "fixtures differ from extraction but aren't marked hand-maintained: {}",
+        stale.join(", ")
+    );
+}
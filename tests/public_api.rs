@@ -0,0 +1,23 @@
+use std::collections::HashMap;
+
+use advent_of_code_2024::{day02, day07, day23, day24};
+
+#[test]
+fn test_part_functions_take_str() {
+    let data = "190: 10 19
+3267: 81 40 27
+292: 11 6 16 20";
+    assert_eq!(day07::part_one(data), 3749);
+    assert_eq!(day07::part_two(data), 3749);
+    assert_eq!(day23::part_two("ka-co\nco-de\nde-ka"), "co,de,ka");
+}
+
+#[test]
+fn test_helpers_are_reusable() {
+    let reports = day02::parse_input("7 6 4 2 1\n1 2 7 8 9").unwrap();
+    assert_eq!(day02::count_safe_reports(&reports), 1);
+
+    let (wires, gates) = day24::parse_input("x00: 1\ny00: 1\n\nx00 XOR y00 -> z00").unwrap();
+    assert_eq!(day24::calculate_value(&wires, &gates[0]), Some(0));
+    assert_eq!(day24::calculate_value(&HashMap::new(), &gates[0]), None);
+}